@@ -0,0 +1,254 @@
+use crate::error::{ParseError, Result};
+use crate::input::{self, InputLine};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    day: usize,
+    file: String,
+    part: usize,
+    expected: String,
+}
+
+impl Answer {
+    pub fn day(&self) -> usize {
+        self.day
+    }
+
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    pub fn part(&self) -> usize {
+        self.part
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Answers {
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    pub fn parse(data: &str) -> Result<Self> {
+        let mut answers = Vec::new();
+        let mut section = None;
+        let mut line_iter = input::lines(data);
+
+        while let Some(line) = line_iter.next() {
+            let text = line.text().trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            if text.starts_with('[') {
+                section = Some(parse_header(line)?);
+                continue;
+            }
+
+            let (day, file) = section.clone().ok_or_else(|| {
+                line.error("Answer found outside of a section.")
+                    .with_expected("a header like '[day1.\"day1.txt\"]'")
+            })?;
+
+            let (key, value) = text.split_once('=').ok_or_else(|| {
+                line.error_at(line.text(), "Expected a key and value.")
+                    .with_expected("an answer like 'part1 = \"42\"'")
+            })?;
+            let key = key.trim();
+            let part = match key {
+                "part1" => 1,
+                "part2" => 2,
+                _ => {
+                    return Err(line
+                        .error_at(key, "Unknown answer key.")
+                        .with_expected("'part1' or 'part2'")
+                        .into())
+                }
+            };
+
+            let value = value.trim();
+            let expected = if let Some(rest) = value.strip_prefix("\"\"\"") {
+                parse_multiline_string(line, rest, &mut line_iter)?
+            } else {
+                parse_string(line, value)?
+            };
+
+            answers.push(Answer {
+                day,
+                file,
+                part,
+                expected,
+            });
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Answer> {
+        self.answers.iter()
+    }
+
+    pub fn files(&self) -> Vec<(usize, &str)> {
+        let mut files: Vec<(usize, &str)> = Vec::new();
+        for answer in self.answers.iter() {
+            let key = (answer.day, answer.file.as_str());
+            if !files.contains(&key) {
+                files.push(key);
+            }
+        }
+        files
+    }
+
+    pub fn get(&self, day: usize, file: &str, part: usize) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.file == file && a.part == part)
+            .map(|a| a.expected.as_str())
+    }
+}
+
+fn parse_header(line: InputLine) -> Result<(usize, String)> {
+    let expected = "a header like '[day1.\"day1.txt\"]'";
+    let header = line
+        .text()
+        .trim()
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or_else(|| {
+            line.error_at(line.text(), "Malformed section header.")
+                .with_expected(expected)
+        })?;
+
+    let (day, file) = header.split_once('.').ok_or_else(|| {
+        line.error_at(header, "Expected a day and file name.")
+            .with_expected(expected)
+    })?;
+    let day = day
+        .strip_prefix("day")
+        .and_then(|d| d.parse::<usize>().ok())
+        .ok_or_else(|| {
+            line.error_at(day, "Unable to parse day.")
+                .with_expected(expected)
+        })?;
+    let file = parse_string(line, file)?;
+
+    Ok((day, file))
+}
+
+fn parse_string(line: InputLine, value: &str) -> Result<String> {
+    let contents = value
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|_| value.len() >= 2)
+        .ok_or_else(|| {
+            line.error_at(value, "Expected a quoted string.")
+                .with_expected("a string like \"42\"")
+        })?;
+    unescape(line, contents)
+}
+
+fn parse_multiline_string<'a>(
+    start: InputLine<'a>,
+    rest: &'a str,
+    line_iter: &mut impl Iterator<Item = InputLine<'a>>,
+) -> Result<String> {
+    if let Some(contents) = rest.strip_suffix("\"\"\"") {
+        return unescape(start, contents);
+    }
+    if !rest.is_empty() {
+        return Err(start
+            .error_at(rest, "Unexpected text after '\"\"\"'.")
+            .with_expected("a line break")
+            .into());
+    }
+
+    let mut contents = Vec::new();
+    for line in line_iter {
+        if let Some(text) = line.text().strip_suffix("\"\"\"") {
+            contents.push(unescape(line, text)?);
+            return Ok(contents.join("\n"));
+        }
+        contents.push(unescape(line, line.text())?);
+    }
+
+    Err(
+        ParseError::new(start.number(), 1, "Unterminated multi-line string.")
+            .with_expected("a closing '\"\"\"'")
+            .into(),
+    )
+}
+
+fn unescape(line: InputLine, text: &str) -> Result<String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some((_, 'n')) => unescaped.push('\n'),
+            Some((_, 't')) => unescaped.push('\t'),
+            Some((_, '"')) => unescaped.push('"'),
+            Some((_, '\\')) => unescaped.push('\\'),
+            _ => {
+                return Err(line
+                    .error_at(&text[i..], "Unknown escape sequence.")
+                    .with_length(2)
+                    .with_expected("one of '\\n', '\\t', '\\\"' or '\\\\'")
+                    .into())
+            }
+        }
+    }
+
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn test_parse() {
+        let data = r#"
+# Expected answers.
+[day13."day13_test.txt"]
+part1 = "17"
+part2 = """
+#####
+#...#
+#####"""
+
+[day25."day25_test.txt"]
+part1 = "say \"58\""
+"#;
+        let answers = Answers::parse(data).unwrap();
+
+        assert_eq!(
+            vec![(13, "day13_test.txt"), (25, "day25_test.txt")],
+            answers.files()
+        );
+        assert_eq!(Some("17"), answers.get(13, "day13_test.txt", 1));
+        assert_eq!(
+            Some("#####\n#...#\n#####"),
+            answers.get(13, "day13_test.txt", 2)
+        );
+        assert_eq!(Some("say \"58\""), answers.get(25, "day25_test.txt", 1));
+        assert_eq!(None, answers.get(25, "day25_test.txt", 2));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Answers::parse("[day1.\"day1.txt\"]\npart3 = \"1\"\n").unwrap_err();
+        match error {
+            Error::Parse(e) => assert_eq!((2, 1), (e.line(), e.column())),
+            e => panic!("Unexpected error: {}", e),
+        }
+    }
+}
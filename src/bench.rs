@@ -0,0 +1,305 @@
+use std::fmt;
+#[cfg(feature = "bench")]
+use std::hint::black_box;
+use std::time::Duration;
+#[cfg(feature = "bench")]
+use std::time::Instant;
+
+#[cfg(feature = "bench")]
+use crate::config::{self, Settings};
+#[cfg(feature = "bench")]
+use crate::error::Result;
+#[cfg(feature = "bench")]
+use crate::input::{self, InputLine};
+#[cfg(feature = "bench")]
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    #[cfg(feature = "bench")]
+    fn from_key(key: &str) -> Option<Self> {
+        Phase::ALL.iter().copied().find(|p| p.key() == key)
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Summary {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len();
+        let median = (sorted[(count - 1) / 2] + sorted[count / 2]) / 2;
+
+        let nanos = sorted.iter().map(|d| d.as_nanos() as f64);
+        let mean = nanos.clone().sum::<f64>() / count as f64;
+        let variance = nanos.map(|n| (n - mean) * (n - mean)).sum::<f64>() / count as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10}  median {:>10}  mean {:>10}  stddev {:>10}",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.mean),
+            format_duration(self.stddev)
+        )
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn samples(&self, phase: Phase) -> &[Duration] {
+        match phase {
+            Phase::Parse => &self.parse,
+            Phase::Part1 => &self.part1,
+            Phase::Part2 => &self.part2,
+        }
+    }
+
+    pub fn summary(&self, phase: Phase) -> Summary {
+        Summary::from_samples(self.samples(phase))
+    }
+}
+
+#[cfg(feature = "bench")]
+pub fn benchmark<S: Solution + Default>(
+    input: &str,
+    settings: &Settings,
+    runs: usize,
+) -> Result<Timings> {
+    let solution = config::configure::<S>(settings)?;
+    let mut timings = Timings::default();
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = black_box(solution.parse(input)?);
+        timings.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        // Keep the optimizer from discarding an answer nobody reads.
+        black_box(solution.part1(&parsed)?);
+        timings.part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(solution.part2(&parsed)?);
+        timings.part2.push(start.elapsed());
+    }
+
+    Ok(timings)
+}
+
+#[cfg(feature = "bench")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaselineEntry {
+    pub day: usize,
+    pub phase: Phase,
+    pub summary: Summary,
+}
+
+#[cfg(feature = "bench")]
+#[derive(Debug, Default, Clone)]
+pub struct Baseline {
+    entries: Vec<BaselineEntry>,
+}
+
+#[cfg(feature = "bench")]
+impl Baseline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, day: usize, phase: Phase, summary: Summary) {
+        self.entries.retain(|e| e.day != day || e.phase != phase);
+        self.entries.push(BaselineEntry {
+            day,
+            phase,
+            summary,
+        });
+    }
+
+    pub fn get(&self, day: usize, phase: Phase) -> Option<&Summary> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.phase == phase)
+            .map(|e| &e.summary)
+    }
+
+    pub fn parse(data: &str) -> Result<Self> {
+        let mut baseline = Self::new();
+
+        for line in input::lines(data) {
+            let text = line.text().trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+            let entry = parse_entry(line)?;
+            baseline.insert(entry.day, entry.phase, entry.summary);
+        }
+
+        Ok(baseline)
+    }
+}
+
+#[cfg(feature = "bench")]
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day phase min_ns median_ns mean_ns stddev_ns")?;
+        for entry in self.entries.iter() {
+            let summary = &entry.summary;
+            writeln!(
+                f,
+                "{} {} {} {} {} {}",
+                entry.day,
+                entry.phase.key(),
+                summary.min.as_nanos(),
+                summary.median.as_nanos(),
+                summary.mean.as_nanos(),
+                summary.stddev.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bench")]
+fn parse_entry(line: InputLine) -> Result<BaselineEntry> {
+    let expected = "a line like '19 part1 1200 1300 1350 40'";
+    let fields = line.text().split_whitespace().collect::<Vec<_>>();
+    if fields.len() != 6 {
+        return Err(line
+            .error_at(line.text(), "Expected six fields.")
+            .with_expected(expected)
+            .into());
+    }
+
+    let day = fields[0].parse::<usize>().map_err(|e| {
+        line.error_at(fields[0], "Unable to parse day.")
+            .with_expected(expected)
+            .with_source(e)
+    })?;
+    let phase = Phase::from_key(fields[1]).ok_or_else(|| {
+        line.error_at(fields[1], "Unknown phase.")
+            .with_expected("'parse', 'part1' or 'part2'")
+    })?;
+
+    let mut nanos = [Duration::default(); 4];
+    for (value, field) in nanos.iter_mut().zip(&fields[2..]) {
+        let parsed = field.parse::<u64>().map_err(|e| {
+            line.error_at(field, "Unable to parse time.")
+                .with_expected("a time in nanoseconds")
+                .with_source(e)
+        })?;
+        *value = Duration::from_nanos(parsed);
+    }
+
+    Ok(BaselineEntry {
+        day,
+        phase,
+        summary: Summary {
+            min: nanos[0],
+            median: nanos[1],
+            mean: nanos[2],
+            stddev: nanos[3],
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let summary = Summary::from_samples(&samples);
+
+        assert_eq!(Duration::from_millis(1), summary.min);
+        assert_eq!(Duration::from_micros(2500), summary.median);
+        assert_eq!(Duration::from_micros(2500), summary.mean);
+        assert_eq!(Duration::from_nanos(1_118_034), summary.stddev);
+    }
+
+    #[cfg(feature = "bench")]
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::new();
+        let summary = Summary {
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(12),
+            mean: Duration::from_nanos(13),
+            stddev: Duration::from_nanos(2),
+        };
+        baseline.insert(19, Phase::Part1, summary);
+
+        let parsed = Baseline::parse(&baseline.to_string()).unwrap();
+        assert_eq!(Some(&summary), parsed.get(19, Phase::Part1));
+        assert_eq!(None, parsed.get(19, Phase::Part2));
+    }
+}
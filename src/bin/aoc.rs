@@ -0,0 +1,723 @@
+use std::env;
+use std::error;
+use std::fmt::Write;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::process;
+#[cfg(feature = "json")]
+use std::time::Duration;
+
+use aoc2021::answers::Answers;
+#[cfg(feature = "bench")]
+use aoc2021::bench::Baseline;
+#[cfg(any(feature = "bench", feature = "json"))]
+use aoc2021::bench::Phase;
+use aoc2021::config::Config;
+use aoc2021::diagnostic::Diagnostic;
+use aoc2021::error::{describe, Error};
+use aoc2021::generate::{self, Rng};
+use aoc2021::instrument::{self, Metrics};
+#[cfg(feature = "json")]
+use aoc2021::json;
+use aoc2021::memo::{self, CacheReport};
+use aoc2021::pool;
+use aoc2021::simulation;
+use aoc2021::snapshot::{self, Snapshot};
+use aoc2021::viz::{self, Palette};
+
+#[cfg(feature = "instrument")]
+#[global_allocator]
+static ALLOCATOR: instrument::CountingAllocator = instrument::CountingAllocator;
+
+fn usage(program: &str) -> String {
+    format!(
+        "Usage: {0} run [options] <day> <input data path | ->...\n       {0} run [options] --all [data directory]\n       {0} run --check [data directory]\n       {0} bench [options] [--runs <n>] [--baseline <file>] [--save <file>] [--threshold <percent>] <day> <input data path>\n       {0} bench [options] --all [data directory]\n       {0} gen [--size <n>] [--seed <n>] <day>\n       {0} step [options] <day> <input data path>\n\nOptions:\n  --format text|json       Output format for results\n  --set day<n>.<key>=<v>   Override a puzzle constant, e.g. day6.part1_days=18\n  --config <file>          Read puzzle constant overrides from a file\n  --stats                  Report memoization cache statistics\n  --instrument             Report solver counters and histograms (needs the instrument feature)\n  --frames <dir>           Write each simulation step as an image (needs the viz feature)\n  --palette <palette>      Frame colours: grey, heat, ocean or a list like 000000,ff8000,ffffff\n  --checkpoint <file>      Save simulation snapshots to a file (days 11, 20 and 25)\n  --checkpoint-every <n>   Steps between snapshots (100 by default)\n  --resume <file>          Resume a simulation from a snapshot\n  --threads <n>            Worker threads for running days and parallel loops (1 by default)\n  --stream                 Solve while reading the input rather than loading it (days 1, 2, 3, 6, 7 and 10;\n                           day 10 keeps a score per incomplete line)",
+        program
+    )
+}
+
+fn display_path(path: &str) -> &str {
+    if path == "-" {
+        "<stdin>"
+    } else {
+        path
+    }
+}
+
+fn read_file(path: &str) -> Result<String, Error> {
+    let result = if path == "-" {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents).map(|_| contents)
+    } else {
+        fs::read_to_string(path)
+    };
+    result.map_err(|e| {
+        let message = format!("{}: {}", display_path(path), e);
+        Error::from(io::Error::new(e.kind(), message))
+    })
+}
+
+fn with_diagnostic(error: Error, contents: &str, path: &str) -> Box<dyn error::Error> {
+    match error {
+        Error::Parse(e) => Box::new(Diagnostic::new(e, contents, display_path(path))),
+        e => Box::new(e),
+    }
+}
+
+fn parse_day(day: &str) -> Result<usize, Box<dyn error::Error>> {
+    day.parse::<usize>()
+        .map_err(|_| format!("Invalid day '{}'.", day).into())
+}
+
+fn missing_day(day: usize) -> Box<dyn error::Error> {
+    if (1..=aoc2021::NUM_DAYS).contains(&day) {
+        format!(
+            "Day {0} is not compiled in; rebuild with '--features day{0:02}'.",
+            day
+        )
+        .into()
+    } else {
+        format!("Unknown day '{}'.", day).into()
+    }
+}
+
+fn is_compiled(day: usize) -> bool {
+    aoc2021::solver(day).is_some()
+}
+
+#[cfg(any(feature = "bench", feature = "json"))]
+fn compiled_days() -> impl Iterator<Item = usize> {
+    (1..=aoc2021::NUM_DAYS).filter(|&day| {
+        let compiled = is_compiled(day);
+        if !compiled {
+            eprintln!("Skipping day {}: not compiled in.", day);
+        }
+        compiled
+    })
+}
+
+fn solve_file(
+    day: usize,
+    path: &str,
+    config: &Config,
+) -> Result<(String, String), Box<dyn error::Error>> {
+    let solve = aoc2021::solver(day).ok_or_else(|| missing_day(day))?;
+    let file_contents = read_file(path)?;
+    solve(&file_contents, &config.settings(day))
+        .map_err(|e| with_diagnostic(e, &file_contents, path))
+}
+
+fn stream_file(
+    day: usize,
+    path: &str,
+    config: &Config,
+) -> Result<(String, String), Box<dyn error::Error>> {
+    let solve = aoc2021::streamer(day).ok_or_else(|| {
+        if is_compiled(day) {
+            format!("Day {} can't be streamed.", day).into()
+        } else {
+            missing_day(day)
+        }
+    })?;
+    let mut reader: Box<dyn BufRead> = if path == "-" {
+        Box::new(io::stdin().lock())
+    } else {
+        let file = fs::File::open(path).map_err(|e| {
+            let message = format!("{}: {}", display_path(path), e);
+            Error::from(io::Error::new(e.kind(), message))
+        })?;
+        Box::new(BufReader::new(file))
+    };
+    // The input isn't kept, so parse errors point at the line without quoting it.
+    solve(&mut reader, &config.settings(day)).map_err(|e| with_diagnostic(e, "", path))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    #[cfg(feature = "json")]
+    Json,
+}
+
+#[derive(Debug, Clone)]
+struct Options {
+    format: Format,
+    config: Config,
+    stats: bool,
+    instrument: bool,
+    frames: Option<viz::Output>,
+    snapshots: snapshot::Options,
+    stream: bool,
+}
+
+impl Options {
+    /// The options for `day` while other days run alongside it.
+    fn for_day(&self, day: usize) -> Self {
+        Self {
+            snapshots: self.snapshots.for_day(day),
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+fn answer_value(answer: String) -> json::Value {
+    if answer.is_empty() {
+        json::Value::Null
+    } else if let Ok(n) = answer.parse::<i64>() {
+        json::Value::from(n)
+    } else if let Ok(n) = answer.parse::<u64>() {
+        json::Value::from(n)
+    } else {
+        json::Value::from(answer)
+    }
+}
+
+#[cfg(feature = "json")]
+fn report_file(
+    day: usize,
+    path: &str,
+    options: &Options,
+) -> Result<json::Value, Box<dyn error::Error>> {
+    let report = aoc2021::reporter(day).ok_or_else(|| missing_day(day))?;
+    let file_contents = read_file(path)?;
+    let ((report, caches), metrics) = record_frames(day, options, || {
+        record_snapshots(day, options, || {
+            instrument::record(|| {
+                memo::record_stats(|| report(&file_contents, &options.config.settings(day)))
+            })
+        })
+    })??;
+    let report = report.map_err(|e| with_diagnostic(e, &file_contents, path))?;
+
+    let mut timings = json::Value::object();
+    for phase in Phase::ALL {
+        let nanos = report
+            .timings
+            .samples(phase)
+            .iter()
+            .sum::<Duration>()
+            .as_nanos();
+        timings = timings.with(format!("{}_ns", phase.key()), nanos as u64);
+    }
+
+    let value = json::Value::object()
+        .with("day", day)
+        .with("input_path", display_path(path))
+        .with("part1", answer_value(report.part1))
+        .with("part2", answer_value(report.part2))
+        .with("timings", timings)
+        .with("extras", report.extras);
+
+    let value = if options.stats {
+        value.with("caches", caches)
+    } else {
+        value
+    };
+
+    if options.instrument {
+        Ok(value.with("instruments", &metrics))
+    } else {
+        Ok(value)
+    }
+}
+
+fn record_frames<T>(
+    day: usize,
+    options: &Options,
+    f: impl FnOnce() -> T,
+) -> Result<T, Box<dyn error::Error>> {
+    let output = match &options.frames {
+        Some(output) => output.with_prefix(format!("day{}", day)),
+        None => return Ok(f()),
+    };
+    let (result, written) = viz::record(&output, f)?;
+    if written == 0 {
+        eprintln!("Day {} has no frames to export.", day);
+    } else {
+        eprintln!("Wrote {} frames to {}.", written, output.dir().display());
+    }
+    Ok(result)
+}
+
+fn record_snapshots<T>(
+    day: usize,
+    options: &Options,
+    f: impl FnOnce() -> T,
+) -> Result<T, Box<dyn error::Error>> {
+    let snapshots = &options.snapshots;
+    if let Some(resume) = snapshots.resume.as_ref().filter(|s| s.day() != day) {
+        eprintln!(
+            "The snapshot is for day {}; day {} starts from its input.",
+            resume.day(),
+            day
+        );
+    }
+    let (result, written) = snapshot::record(snapshots, f)?;
+    if let Some(path) = snapshots.checkpoint.as_ref().filter(|_| written > 0) {
+        eprintln!("Wrote {} snapshots to {}.", written, path.display());
+    }
+    Ok(result)
+}
+
+fn write_caches(output: &mut String, caches: &[CacheReport]) {
+    if caches.is_empty() {
+        output.push_str("No memoization caches used.\n");
+    }
+    for cache in caches {
+        writeln!(output, "Cache {}", cache).unwrap();
+    }
+}
+
+fn write_metrics(output: &mut String, metrics: &Metrics) {
+    if metrics.is_empty() {
+        output.push_str("No instrumentation recorded.\n");
+    }
+    write!(output, "{}", metrics).unwrap();
+}
+
+fn run_day(day: usize, path: &str, options: &Options) -> Result<(), Box<dyn error::Error>> {
+    #[cfg(feature = "json")]
+    if options.format == Format::Json {
+        println!("{}", report_file(day, path, options)?);
+        return Ok(());
+    }
+
+    print!("{}", day_output(day, path, options)?);
+    Ok(())
+}
+
+fn day_output(day: usize, path: &str, options: &Options) -> Result<String, Box<dyn error::Error>> {
+    let ((results, caches), metrics) = record_frames(day, options, || {
+        record_snapshots(day, options, || {
+            let solve = if options.stream {
+                stream_file
+            } else {
+                solve_file
+            };
+            instrument::record(|| memo::record_stats(|| solve(day, path, &options.config)))
+        })
+    })??;
+    let (part1, part2) = results?;
+
+    let mut output = String::new();
+    for (name, answer) in [("one", part1), ("two", part2)] {
+        if answer.is_empty() {
+            continue;
+        }
+        if answer.contains('\n') {
+            writeln!(output, "Part {}:\n{}", name, answer).unwrap();
+        } else {
+            writeln!(output, "Part {}: {}", name, answer).unwrap();
+        }
+    }
+
+    if options.stats {
+        write_caches(&mut output, &caches);
+    }
+
+    if options.instrument {
+        write_metrics(&mut output, &metrics);
+    }
+
+    Ok(output)
+}
+
+fn run_files(day: usize, paths: &[&str], options: &Options) -> Result<(), Box<dyn error::Error>> {
+    if let [path] = paths {
+        return run_day(day, path, options);
+    }
+
+    #[cfg(feature = "json")]
+    if options.format == Format::Json {
+        let reports = paths
+            .iter()
+            .map(|path| report_file(day, path, options))
+            .collect::<Result<Vec<_>, _>>()?;
+        println!("{}", json::Value::Array(reports));
+        return Ok(());
+    }
+
+    for path in paths {
+        println!("{}", display_path(path));
+        run_day(day, path, options)?;
+    }
+
+    Ok(())
+}
+
+/// Runs every day on the pool, printing each day's output in order once it is
+/// done. Errors are rendered on the worker since they cannot cross threads.
+fn run_all(data_dir: &str, options: &Options) -> Result<(), Box<dyn error::Error>> {
+    #[cfg(feature = "json")]
+    if options.format == Format::Json {
+        let days = compiled_days().collect::<Vec<_>>();
+        let reports = pool::map(&days, |&day| {
+            let path = format!("{}/day{}.txt", data_dir, day);
+            report_file(day, &path, &options.for_day(day)).map_err(|e| describe(&*e))
+        });
+        let reports = reports.into_iter().collect::<Result<Vec<_>, _>>()?;
+        println!("{}", json::Value::Array(reports));
+        return Ok(());
+    }
+
+    let days = (1..=aoc2021::NUM_DAYS).collect::<Vec<_>>();
+    let outputs = pool::map(&days, |&day| {
+        if !is_compiled(day) {
+            return (format!("Day {}: not compiled in\n", day), None);
+        }
+        let header = format!("Day {}\n", day);
+        let path = format!("{}/day{}.txt", data_dir, day);
+        match day_output(day, &path, &options.for_day(day)) {
+            Ok(output) => (header + &output, None),
+            Err(e) => (header, Some(describe(&*e))),
+        }
+    });
+
+    for (output, error) in outputs {
+        print!("{}", output);
+        if let Some(error) = error {
+            return Err(error.into());
+        }
+    }
+
+    Ok(())
+}
+
+fn run_check(data_dir: &str) -> Result<(), Box<dyn error::Error>> {
+    let answers_path = format!("{}/answers.toml", data_dir);
+    let answers_data = read_file(&answers_path)?;
+    let answers = Answers::parse(&answers_data)
+        .map_err(|e| with_diagnostic(e, &answers_data, &answers_path))?;
+
+    let files = answers.files();
+    let solved = pool::map(&files, |&(day, file)| {
+        let path = format!("{}/{}", data_dir, file);
+        is_compiled(day).then(|| solve_file(day, &path, &Config::new()).map_err(|e| describe(&*e)))
+    });
+
+    let mut failures = 0;
+    for (&(day, file), solved) in files.iter().zip(solved) {
+        let results = match solved {
+            None => {
+                println!("Day {} {}: skipped (not compiled in)", day, file);
+                continue;
+            }
+            Some(Ok((part1, part2))) => [part1, part2],
+            Some(Err(e)) => {
+                println!("Day {} {}: FAILED", day, file);
+                println!("{}", e);
+                failures += 1;
+                continue;
+            }
+        };
+
+        let mut mismatched = false;
+        for (part, result) in results.iter().enumerate().map(|(i, r)| (i + 1, r)) {
+            let expected = match answers.get(day, file, part) {
+                Some(expected) => expected,
+                None => continue,
+            };
+            if expected != result {
+                println!("Day {} {} part {}: MISMATCH", day, file, part);
+                println!("  expected: {}", expected);
+                println!("  actual:   {}", result);
+                mismatched = true;
+            }
+        }
+
+        if mismatched {
+            failures += 1;
+        } else {
+            println!("Day {} {}: ok", day, file);
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} of {} checks failed.", failures, files.len()).into());
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "bench")]
+struct BenchOptions {
+    runs: usize,
+    baseline: Option<String>,
+    save: Option<String>,
+    threshold: f64,
+}
+
+fn option_value<T: std::str::FromStr>(
+    name: &str,
+    value: Option<&&str>,
+) -> Result<T, Box<dyn error::Error>> {
+    value
+        .and_then(|v| v.parse::<T>().ok())
+        .ok_or_else(|| format!("Invalid or missing value for '{}'.", name).into())
+}
+
+#[cfg(feature = "bench")]
+fn run_bench(program: &str, args: &[&str], config: &Config) -> Result<(), Box<dyn error::Error>> {
+    let mut options = BenchOptions {
+        runs: 10,
+        baseline: None,
+        save: None,
+        threshold: 10.0,
+    };
+    let mut positional = Vec::new();
+
+    let mut arg_iter = args.iter();
+    while let Some(&arg) = arg_iter.next() {
+        match arg {
+            "--runs" => options.runs = option_value(arg, arg_iter.next())?,
+            "--baseline" => options.baseline = Some(option_value(arg, arg_iter.next())?),
+            "--save" => options.save = Some(option_value(arg, arg_iter.next())?),
+            "--threshold" => options.threshold = option_value(arg, arg_iter.next())?,
+            _ => positional.push(arg),
+        }
+    }
+    if options.runs == 0 {
+        return Err("At least one run is required.".into());
+    }
+
+    let targets = match positional[..] {
+        ["--all"] => compiled_days()
+            .map(|day| (day, format!("data/day{}.txt", day)))
+            .collect(),
+        ["--all", data_dir] => compiled_days()
+            .map(|day| (day, format!("{}/day{}.txt", data_dir, day)))
+            .collect(),
+        [day, path] => vec![(parse_day(day)?, path.to_string())],
+        _ => return Err(usage(program).into()),
+    };
+
+    let previous = match &options.baseline {
+        Some(path) => {
+            let data = read_file(path)?;
+            Some(Baseline::parse(&data).map_err(|e| with_diagnostic(e, &data, path))?)
+        }
+        None => None,
+    };
+
+    let mut baseline = previous.clone().unwrap_or_default();
+    let mut regressions = 0;
+
+    for (day, path) in targets {
+        let benchmark = aoc2021::benchmark(day).ok_or_else(|| missing_day(day))?;
+        let file_contents = read_file(&path)?;
+        let timings = benchmark(&file_contents, &config.settings(day), options.runs)
+            .map_err(|e| with_diagnostic(e, &file_contents, &path))?;
+
+        println!("Day {} ({} runs)", day, options.runs);
+        for phase in Phase::ALL {
+            let summary = timings.summary(phase);
+            print!("  {:<6}  {}", phase.to_string(), summary);
+
+            if let Some(old) = previous.as_ref().and_then(|b| b.get(day, phase)) {
+                let old_median = old.median.as_secs_f64();
+                let change = if old_median > 0.0 {
+                    (summary.median.as_secs_f64() - old_median) / old_median * 100.0
+                } else {
+                    0.0
+                };
+                print!("  {:+.1}%", change);
+                if change > options.threshold {
+                    print!(" REGRESSION");
+                    regressions += 1;
+                }
+            }
+            println!();
+
+            baseline.insert(day, phase, summary);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        fs::write(path, baseline.to_string())
+            .map_err(|e| Error::from(io::Error::new(e.kind(), format!("{}: {}", path, e))))?;
+    }
+
+    if regressions > 0 {
+        let message = format!(
+            "{} phases regressed by more than {}% against the baseline.",
+            regressions, options.threshold
+        );
+        return Err(message.into());
+    }
+
+    Ok(())
+}
+
+fn run_gen(program: &str, args: &[&str]) -> Result<(), Box<dyn error::Error>> {
+    let mut size = None;
+    let mut seed = 0;
+    let mut positional = Vec::new();
+
+    let mut arg_iter = args.iter();
+    while let Some(&arg) = arg_iter.next() {
+        match arg {
+            "--size" => size = Some(option_value(arg, arg_iter.next())?),
+            "--seed" => seed = option_value(arg, arg_iter.next())?,
+            _ => positional.push(arg),
+        }
+    }
+
+    let day = match positional[..] {
+        [day] => parse_day(day)?,
+        _ => return Err(usage(program).into()),
+    };
+    let generator = generate::generator(day).ok_or_else(|| format!("Unknown day '{}'.", day))?;
+    let input = (generator.generate)(&mut Rng::new(seed), size.unwrap_or(generator.default_size));
+    print!("{}", input);
+
+    Ok(())
+}
+
+fn run_step(program: &str, args: &[&str], config: &Config) -> Result<(), Box<dyn error::Error>> {
+    let (day, path) = match args {
+        [day, path] => (parse_day(day)?, *path),
+        _ => return Err(usage(program).into()),
+    };
+    if path == "-" {
+        return Err("'step' reads commands from stdin, so the input must be a file.".into());
+    }
+
+    let start = aoc2021::simulator(day).ok_or_else(|| missing_day(day))?;
+    let file_contents = read_file(path)?;
+    let mut simulation = start(&file_contents, &config.settings(day))
+        .map_err(|e| with_diagnostic(e, &file_contents, path))?
+        .ok_or_else(|| format!("Day {} has no step mode.", day))?;
+
+    simulation::interact(simulation.as_mut(), io::stdin().lock(), io::stdout().lock())?;
+    Ok(())
+}
+
+fn extract_options<'a>(args: &[&'a str]) -> Result<(Options, Vec<&'a str>), Box<dyn error::Error>> {
+    let mut options = Options {
+        format: Format::Text,
+        config: Config::new(),
+        stats: false,
+        instrument: false,
+        frames: None,
+        snapshots: snapshot::Options::default(),
+        stream: false,
+    };
+    let mut frames_dir = None;
+    let mut palette = Palette::grey();
+    let mut overrides = Vec::new();
+    let mut remaining = Vec::new();
+
+    let mut arg_iter = args.iter();
+    while let Some(&arg) = arg_iter.next() {
+        match arg {
+            "--format" => {
+                options.format = match arg_iter.next() {
+                    Some(&"text") => Format::Text,
+                    #[cfg(feature = "json")]
+                    Some(&"json") => Format::Json,
+                    #[cfg(not(feature = "json"))]
+                    Some(&"json") => {
+                        return Err(
+                            "'--format json' requires building with '--features json'.".into()
+                        )
+                    }
+                    Some(other) => return Err(format!("Unknown format '{}'.", other).into()),
+                    None => return Err("Missing value for '--format'.".into()),
+                }
+            }
+            "--config" => {
+                let path: String = option_value(arg, arg_iter.next())?;
+                let data = read_file(&path)?;
+                options.config =
+                    Config::parse(&data).map_err(|e| with_diagnostic(e, &data, &path))?;
+            }
+            "--stats" => options.stats = true,
+            "--stream" => options.stream = true,
+            "--instrument" if instrument::ENABLED => options.instrument = true,
+            "--instrument" => {
+                return Err("'--instrument' requires building with '--features instrument'.".into())
+            }
+            "--frames" if viz::ENABLED => {
+                frames_dir = Some(option_value::<String>(arg, arg_iter.next())?)
+            }
+            "--frames" => return Err("'--frames' requires building with '--features viz'.".into()),
+            "--palette" => palette = option_value::<String>(arg, arg_iter.next())?.parse()?,
+            "--checkpoint" => {
+                options.snapshots.checkpoint =
+                    Some(option_value::<String>(arg, arg_iter.next())?.into())
+            }
+            "--checkpoint-every" => {
+                options.snapshots.interval = option_value(arg, arg_iter.next())?;
+                if options.snapshots.interval == 0 {
+                    return Err("'--checkpoint-every' must be at least 1.".into());
+                }
+            }
+            "--resume" => {
+                let path: String = option_value(arg, arg_iter.next())?;
+                let data = read_file(&path)?;
+                let snapshot = data
+                    .parse::<Snapshot>()
+                    .map_err(|e| with_diagnostic(e, &data, &path))?;
+                options.snapshots.resume = Some(snapshot.with_path(display_path(&path)));
+            }
+            "--threads" => {
+                let threads = option_value(arg, arg_iter.next())?;
+                if threads == 0 {
+                    return Err("'--threads' must be at least 1.".into());
+                }
+                pool::set_threads(threads);
+            }
+            "--set" => overrides.push(option_value::<String>(arg, arg_iter.next())?),
+            _ => remaining.push(arg),
+        }
+    }
+
+    for spec in overrides {
+        options.config.set_override(&spec)?;
+    }
+    options.frames = frames_dir.map(|dir| viz::Output::new(dir, palette));
+
+    Ok((options, remaining))
+}
+
+fn run(args: &[&str]) -> Result<(), Box<dyn error::Error>> {
+    let (options, args) = extract_options(args)?;
+
+    match args.get(1) {
+        #[cfg(feature = "bench")]
+        Some(&"bench") => return run_bench(args[0], &args[2..], &options.config),
+        #[cfg(not(feature = "bench"))]
+        Some(&"bench") => return Err("'bench' requires building with '--features bench'.".into()),
+        Some(&"gen") => return run_gen(args[0], &args[2..]),
+        Some(&"step") => return run_step(args[0], &args[2..], &options.config),
+        _ => {}
+    }
+
+    let text_only = options.format == Format::Text;
+    if options.stream
+        && !(text_only && matches!(args[1..], ["run", day, _, ..] if !day.starts_with("--")))
+    {
+        return Err("'--stream' only applies to 'run <day>' with text output.".into());
+    }
+    match args[1..] {
+        ["run", "--all"] => run_all("data", &options),
+        ["run", "--all", data_dir] => run_all(data_dir, &options),
+        ["run", "--check"] if text_only => run_check("data"),
+        ["run", "--check", data_dir] if text_only => run_check(data_dir),
+        ["run", day, ref paths @ ..] if !paths.is_empty() => {
+            run_files(parse_day(day)?, paths, &options)
+        }
+        _ => Err(usage(args[0]).into()),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+
+    if let Err(e) = run(&args) {
+        eprintln!("{}", describe(&*e));
+        process::exit(1);
+    }
+}
@@ -0,0 +1,165 @@
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::input::{self, InputLine};
+use crate::Solution;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Settings {
+    values: Vec<(String, String)>,
+}
+
+impl Settings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+        self.values.retain(|(k, _)| *k != key);
+        self.values.push((key, value.into()));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    days: Vec<(usize, Settings)>,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, day: usize, key: impl Into<String>, value: impl Into<String>) {
+        if let Some((_, settings)) = self.days.iter_mut().find(|(d, _)| *d == day) {
+            settings.set(key, value);
+        } else {
+            let mut settings = Settings::new();
+            settings.set(key, value);
+            self.days.push((day, settings));
+        }
+    }
+
+    pub fn settings(&self, day: usize) -> Settings {
+        self.days
+            .iter()
+            .find(|(d, _)| *d == day)
+            .map(|(_, s)| s.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn set_override(&mut self, spec: &str) -> Result<()> {
+        let expected = "an override like 'day6.part1_days=18'";
+        let (key, value) = spec
+            .split_once('=')
+            .ok_or_else(|| Error::invalid_config(format!("Expected {}.", expected)))?;
+        let (day, key) = key
+            .split_once('.')
+            .ok_or_else(|| Error::invalid_config(format!("Expected {}.", expected)))?;
+        let day = day
+            .strip_prefix("day")
+            .and_then(|d| d.parse::<usize>().ok())
+            .ok_or_else(|| Error::invalid_config(format!("Invalid day '{}'.", day)))?;
+
+        self.set(day, key.trim(), value.trim());
+        Ok(())
+    }
+
+    pub fn parse(data: &str) -> Result<Self> {
+        let mut config = Self::new();
+        let mut day = None;
+
+        for line in input::lines(data) {
+            let text = line.text().trim();
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            if text.starts_with('[') {
+                day = Some(parse_header(line)?);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| {
+                line.error("Setting found outside of a section.")
+                    .with_expected("a header like '[day6]'")
+            })?;
+            let (key, value) = text.split_once('=').ok_or_else(|| {
+                line.error_at(line.text(), "Expected a key and value.")
+                    .with_expected("a setting like 'part1_days = 18'")
+            })?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+
+            config.set(day, key.trim(), value);
+        }
+
+        Ok(config)
+    }
+}
+
+fn parse_header(line: InputLine) -> Result<usize> {
+    let text = line.text().trim();
+    text.strip_prefix("[day")
+        .and_then(|s| s.strip_suffix(']'))
+        .and_then(|s| s.parse::<usize>().ok())
+        .ok_or_else(|| {
+            line.error_at(text, "Malformed section header.")
+                .with_expected("a header like '[day6]'")
+                .into()
+        })
+}
+
+pub fn parse_value<T: FromStr>(key: &str, value: &str) -> Result<T> {
+    value
+        .parse::<T>()
+        .map_err(|_| Error::invalid_config(format!("Invalid value '{}' for '{}'.", value, key)))
+}
+
+pub fn unknown_key(key: &str) -> Error {
+    Error::invalid_config(format!("Unknown setting '{}'.", key))
+}
+
+pub fn configure<S: Solution + Default>(settings: &Settings) -> Result<S> {
+    let mut solution = S::default();
+    for (key, value) in settings.iter() {
+        solution.configure(key, value)?;
+    }
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let data = "# What-if study.\n[day6]\npart1_days = 18\n\n[day21]\nboard_size = \"12\"\n";
+        let mut config = Config::parse(data).unwrap();
+        config.set_override("day6.part2_days=100").unwrap();
+
+        let settings = config.settings(6);
+        assert_eq!(
+            vec![("part1_days", "18"), ("part2_days", "100")],
+            settings.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![("board_size", "12")],
+            config.settings(21).iter().collect::<Vec<_>>()
+        );
+        assert!(config.settings(1).is_empty());
+        assert!(config.set_override("day6=18").is_err());
+    }
+}
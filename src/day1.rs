@@ -0,0 +1,109 @@
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::input::{self, InputLine};
+use crate::solution::Streaming;
+use crate::Solution;
+
+/// The depth measurements from a sonar sweep, one per line.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SonarSweep {
+    depths: Vec<i32>,
+}
+
+impl SonarSweep {
+    pub fn new(depths: Vec<i32>) -> Self {
+        Self { depths }
+    }
+
+    pub fn depths(&self) -> &[i32] {
+        &self.depths
+    }
+}
+
+impl FromStr for SonarSweep {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let depths = input::lines(s)
+            .map(parse_depth)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(depths))
+    }
+}
+
+impl fmt::Display for SonarSweep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for depth in self.depths.iter() {
+            writeln!(f, "{}", depth)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_depth(line: InputLine) -> Result<i32> {
+    line.text()
+        .parse::<i32>()
+        .map_err(|e| line.error("Unable to parse depth.").with_source(e).into())
+}
+
+fn count_depth_changes(data: &[i32], offset: usize) -> i32 {
+    let mut count = 0;
+
+    for (i, depth) in data.iter().enumerate() {
+        if i < offset {
+            continue;
+        }
+        let prev_depth = data[i - offset];
+        if *depth > prev_depth {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = SonarSweep;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        Ok(count_depth_changes(input.depths(), 1))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        Ok(count_depth_changes(input.depths(), 3))
+    }
+}
+
+impl Streaming for Day1 {
+    fn solve_stream(&self, reader: &mut dyn BufRead) -> Result<(i32, i32)> {
+        let mut lines = input::Reader::lines(reader);
+        // The last three depths, with depth `i` at `i % 3`.
+        let mut recent = [0; 3];
+        let (mut part1, mut part2) = (0, 0);
+        let mut i = 0;
+        while let Some(line) = lines.next_record()? {
+            let depth = parse_depth(line)?;
+            if i >= 1 && depth > recent[(i - 1) % 3] {
+                part1 += 1;
+            }
+            if i >= 3 && depth > recent[i % 3] {
+                part2 += 1;
+            }
+            recent[i % 3] = depth;
+            i += 1;
+        }
+        Ok((part1, part2))
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::input::{self, InputLine};
+use crate::solution::Streaming;
+use crate::Solution;
+
+const BRACKETS: &str = "()[]{}<>";
+
+/// The lines of the navigation subsystem, made up only of brackets.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NavigationSubsystem {
+    lines: Vec<String>,
+}
+
+impl NavigationSubsystem {
+    pub fn new(lines: Vec<String>) -> Result<Self> {
+        if let Some(c) = lines
+            .iter()
+            .flat_map(|l| l.chars())
+            .find(|&c| !BRACKETS.contains(c))
+        {
+            return Err(Error::invalid_state(format!(
+                "Unexpected character '{}'.",
+                c
+            )));
+        }
+        Ok(Self { lines })
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+}
+
+impl FromStr for NavigationSubsystem {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let lines = input::lines(s)
+            .map(|line| parse_line(line).map(str::to_string))
+            .collect::<Result<_>>()?;
+        Ok(Self { lines })
+    }
+}
+
+impl fmt::Display for NavigationSubsystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines.iter() {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_line(line: InputLine<'_>) -> Result<&str> {
+    let text = line.text();
+    if let Some((i, c)) = text.char_indices().find(|&(_, c)| !BRACKETS.contains(c)) {
+        let message = format!("Unexpected character '{}'.", c);
+        return Err(line.error_at(&text[i..], message).into());
+    }
+    Ok(text)
+}
+
+fn compute_line_score(line: &str) -> Result<(bool, usize, usize)> {
+    let mut stack = Vec::new();
+
+    macro_rules! closing_case {
+        ($open:expr, $value:expr) => {
+            if let Some(p) = stack.pop() {
+                if p != $open {
+                    return Ok((false, $value, 0));
+                }
+            } else {
+                return Ok((false, $value, 0));
+            }
+        };
+    }
+
+    for c in line.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            ')' => closing_case!('(', 3),
+            ']' => closing_case!('[', 57),
+            '}' => closing_case!('{', 1197),
+            '>' => closing_case!('<', 25137),
+            _ => {
+                return Err(Error::invalid_state(format!(
+                    "Unexpected character '{}'.",
+                    c
+                )))
+            }
+        }
+    }
+
+    if stack.is_empty() {
+        return Ok((false, 0, 0));
+    }
+
+    let mut completion_score: usize = 0;
+
+    for c in stack.iter().rev() {
+        let value = match c {
+            '(' => 1,
+            '[' => 2,
+            '{' => 3,
+            '<' => 4,
+            _ => unreachable!(),
+        };
+        completion_score = completion_score
+            .checked_mul(5)
+            .and_then(|score| score.checked_add(value))
+            .ok_or_else(|| Error::unsolvable("Completion score overflows."))?;
+    }
+
+    Ok((true, 0, completion_score))
+}
+
+fn median_completion_score(mut completion_scores: Vec<usize>) -> Result<usize> {
+    completion_scores.sort_unstable();
+    let midpoint = completion_scores.len() / 2;
+    completion_scores
+        .get(midpoint)
+        .copied()
+        .ok_or_else(|| Error::unsolvable("No incomplete lines in input."))
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = NavigationSubsystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let mut corruption_score = 0;
+
+        for l in input.lines() {
+            let (_, line_corruption_score, _) = compute_line_score(l)?;
+            corruption_score += line_corruption_score;
+        }
+
+        Ok(corruption_score)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let mut completion_scores = Vec::new();
+
+        for l in input.lines() {
+            let (incomplete, _, line_completion_score) = compute_line_score(l)?;
+            if incomplete {
+                completion_scores.push(line_completion_score);
+            }
+        }
+        median_completion_score(completion_scores)
+    }
+}
+
+impl Streaming for Day10 {
+    /// Only the completion scores of incomplete lines are kept, for the
+    /// median, so memory grows with the number of incomplete lines.
+    fn solve_stream(&self, reader: &mut dyn BufRead) -> Result<(usize, usize)> {
+        let mut lines = input::Reader::lines(reader);
+        let mut corruption_score = 0;
+        let mut completion_scores = Vec::new();
+        while let Some(line) = lines.next_record()? {
+            let (incomplete, line_corruption_score, line_completion_score) =
+                compute_line_score(parse_line(line)?)?;
+            corruption_score += line_corruption_score;
+            if incomplete {
+                completion_scores.push(line_completion_score);
+            }
+        }
+        Ok((
+            corruption_score,
+            median_completion_score(completion_scores)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deep_completion_overflows() {
+        let line = "([{<".repeat(10);
+        let input = line.parse::<NavigationSubsystem>().unwrap();
+        assert!(matches!(Day10.part2(&input), Err(Error::Unsolvable(_))));
+        assert!(matches!(
+            Day10.solve_stream(&mut line.as_bytes()),
+            Err(Error::Unsolvable(_))
+        ));
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config;
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::simulation::Simulation;
+use crate::snapshot::{self, Snapshot};
+use crate::traverse;
+use crate::viz::{self, Frame};
+use crate::Solution;
+
+/// The energy level of each dumbo octopus, one digit per octopus.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OctopusGrid {
+    energies: Grid<u8>,
+}
+
+impl OctopusGrid {
+    pub fn new(energies: Grid<u8>) -> Self {
+        Self { energies }
+    }
+
+    pub fn energies(&self) -> &Grid<u8> {
+        &self.energies
+    }
+
+    pub fn size(&self) -> usize {
+        self.energies.len()
+    }
+
+    /// Advances one step and returns how many octopuses flashed.
+    pub fn step(&mut self) -> usize {
+        for e in self.energies.iter_mut() {
+            *e += 1;
+        }
+
+        let energies = &mut self.energies;
+        let charged = energies
+            .positions()
+            .filter(|&p| energies[p] > 9)
+            .collect::<Vec<_>>();
+
+        // Each flash charges its neighbours, and the traversal visits every
+        // neighbour pushed over the threshold exactly once.
+        let flashed = traverse::bfs(charged, |&(i, j)| {
+            let neighbours = energies.neighbours8(i, j).collect::<Vec<_>>();
+            neighbours
+                .into_iter()
+                .filter(|&n| {
+                    energies[n] += 1;
+                    energies[n] > 9
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+        for &position in flashed.iter() {
+            self.energies[position] = 0;
+        }
+
+        flashed.len()
+    }
+
+    /// Flashing octopuses are brightest; the rest shade by energy.
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.energies, |&e| if e == 0 { 255 } else { e * 20 })
+    }
+}
+
+impl fmt::Display for OctopusGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.energies)
+    }
+}
+
+impl FromStr for OctopusGrid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Self::new(Grid::parse_digits(s)?))
+    }
+}
+
+impl Simulation for OctopusGrid {
+    fn step(&mut self) -> Option<String> {
+        let flash_count = OctopusGrid::step(self);
+        (flash_count == self.size())
+            .then(|| format!("All {} octopuses flashed together.", flash_count))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day11 {
+    part1_steps: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self { part1_steps: 100 }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = OctopusGrid;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let (mut grid, mut step_count, mut flash_count) = match snapshot::resume(11, 1) {
+            Some(snapshot) => (
+                snapshot.state::<OctopusGrid>()?,
+                snapshot.step(),
+                snapshot.value("flashes")?,
+            ),
+            None => (input.clone(), 0, 0),
+        };
+
+        viz::frame(|| grid.frame());
+        while step_count < self.part1_steps {
+            flash_count += grid.step();
+            step_count += 1;
+            viz::frame(|| grid.frame());
+            snapshot::checkpoint(step_count, || {
+                Snapshot::new(11, 1, step_count, &grid).with("flashes", flash_count)
+            });
+        }
+        Ok(flash_count)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let (mut grid, mut step_count) = match snapshot::resume(11, 2) {
+            Some(snapshot) => (snapshot.state::<OctopusGrid>()?, snapshot.step()),
+            None => (input.clone(), 0),
+        };
+
+        viz::frame(|| grid.frame());
+        loop {
+            let flash_count = grid.step();
+            step_count += 1;
+            viz::frame(|| grid.frame());
+            if flash_count == grid.size() {
+                break;
+            }
+            snapshot::checkpoint(step_count, || Snapshot::new(11, 2, step_count, &grid));
+        }
+        Ok(step_count)
+    }
+
+    fn simulation(&self, input: &Self::Input) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(input.clone()))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "part1_steps" => self.part1_steps = config::parse_value(key, value)?,
+            _ => return Err(config::unknown_key(key)),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::input;
+use crate::traverse;
+use crate::Solution;
+
+trait Path: Clone {
+    fn can_visit_node(&self, node: &str) -> bool;
+    fn visit_node(&mut self, node: &str);
+    fn current_node(&self) -> Option<String>;
+}
+
+#[derive(Debug, Default, Clone)]
+struct FastPath {
+    current_node: Option<String>,
+    node_counter: HashMap<String, usize>,
+}
+
+impl FastPath {
+    fn new(init_node: &str) -> Self {
+        let mut node_counter = HashMap::new();
+        node_counter.insert(init_node.to_string(), 1);
+        Self {
+            current_node: Some(init_node.to_string()),
+            node_counter,
+        }
+    }
+}
+
+impl Path for FastPath {
+    fn can_visit_node(&self, node: &str) -> bool {
+        if node == "start" {
+            return false;
+        }
+        let count = self.node_counter.get(node).copied().unwrap_or_default();
+        count == 0 || node.to_ascii_uppercase() == node
+    }
+
+    fn visit_node(&mut self, node: &str) {
+        let counter = self.node_counter.entry(node.to_string()).or_default();
+        *counter += 1;
+
+        self.current_node = Some(node.to_string());
+    }
+
+    fn current_node(&self) -> Option<String> {
+        self.current_node.clone()
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct ScenicPath {
+    current_node: Option<String>,
+    node_counter: HashMap<String, usize>,
+    small_node_quota_reached: bool,
+}
+
+impl ScenicPath {
+    fn new(init_node: &str) -> Self {
+        let mut node_counter = HashMap::new();
+        node_counter.insert(init_node.to_string(), 1);
+        Self {
+            current_node: Some(init_node.to_string()),
+            node_counter,
+            small_node_quota_reached: false,
+        }
+    }
+}
+
+impl Path for ScenicPath {
+    fn can_visit_node(&self, node: &str) -> bool {
+        if node == "start" {
+            return false;
+        }
+        let count = self.node_counter.get(node).copied().unwrap_or_default();
+        count == 0
+            || (count < 2 && !self.small_node_quota_reached)
+            || node.to_ascii_uppercase() == node
+    }
+
+    fn visit_node(&mut self, node: &str) {
+        let counter = self.node_counter.entry(node.to_string()).or_default();
+        *counter += 1;
+
+        if *counter == 2 && node.to_ascii_lowercase() == node {
+            self.small_node_quota_reached = true;
+        }
+
+        self.current_node = Some(node.to_string());
+    }
+
+    fn current_node(&self) -> Option<String> {
+        self.current_node.clone()
+    }
+}
+
+/// The map of the cave system: a list of connections between caves, written
+/// one per line as `a-b`. Caves with upper case names are big.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CaveSystem {
+    connections: Vec<(String, String)>,
+    neighbours: HashMap<String, Vec<String>>,
+}
+
+impl CaveSystem {
+    pub fn new(connections: Vec<(String, String)>) -> Self {
+        let mut neighbours: HashMap<_, Vec<_>> = HashMap::new();
+        for (first, second) in connections.iter() {
+            if second != "start" {
+                let entry = neighbours.entry(first.clone()).or_default();
+                entry.push(second.clone());
+            }
+            if first != "start" {
+                let entry = neighbours.entry(second.clone()).or_default();
+                entry.push(first.clone());
+            }
+        }
+        Self {
+            connections,
+            neighbours,
+        }
+    }
+
+    pub fn connections(&self) -> &[(String, String)] {
+        &self.connections
+    }
+
+    /// The caves reachable from `cave` in one step. Nothing leads back to
+    /// `start`.
+    pub fn neighbours(&self, cave: &str) -> &[String] {
+        self.neighbours.get(cave).map_or(&[], |n| n.as_slice())
+    }
+}
+
+impl FromStr for CaveSystem {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl fmt::Display for CaveSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (first, second) in self.connections.iter() {
+            writeln!(f, "{}-{}", first, second)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(data: &str) -> Result<CaveSystem> {
+    let mut connections = Vec::new();
+
+    for line in input::lines(data) {
+        let mut split_iter = line.text().split('-');
+        let first = split_iter.next().unwrap_or_default();
+        if first.is_empty() {
+            return Err(line.error("Expected a cave name.").into());
+        }
+        let second = split_iter
+            .next()
+            .ok_or_else(|| line.error_at_end("Expected '-' followed by a cave name."))?;
+        connections.push((first.to_string(), second.to_string()));
+    }
+
+    Ok(CaveSystem::new(connections))
+}
+
+fn find_all_paths<P: Path>(caves: &CaveSystem, init_path: P) -> Result<Vec<P>> {
+    let extend_path = |path: &P| match path.current_node() {
+        Some(node) if node != "end" => caves
+            .neighbours(&node)
+            .iter()
+            .filter(|neighbour| path.can_visit_node(neighbour))
+            .map(|neighbour| {
+                let mut new_path = path.clone();
+                new_path.visit_node(neighbour);
+                new_path
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let mut paths = Vec::new();
+    for path in traverse::bfs_tree([init_path], extend_path) {
+        let node = path
+            .current_node()
+            .ok_or_else(|| Error::invalid_state("Path is empty."))?;
+        if node == "end" {
+            paths.push(path);
+        }
+    }
+
+    Ok(paths)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = CaveSystem;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        Ok(find_all_paths(input, FastPath::new("start"))?.len())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        Ok(find_all_paths(input, ScenicPath::new("start"))?.len())
+    }
+}
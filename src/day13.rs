@@ -0,0 +1,277 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::input::{self, InputLine};
+#[cfg(feature = "json")]
+use crate::json;
+use crate::Solution;
+
+/// A dot on the transparent paper, as `(x, y)`.
+pub type Point = (usize, usize);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Hash, Default)]
+pub enum Axis {
+    #[default]
+    X,
+    Y,
+}
+
+impl fmt::Display for Axis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Axis::X => f.write_str("x"),
+            Axis::Y => f.write_str("y"),
+        }
+    }
+}
+
+/// A fold along a line of the paper, written as `fold along y=7`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Fold {
+    pos: usize,
+    axis: Axis,
+}
+
+impl Fold {
+    pub fn new(pos: usize, axis: Axis) -> Self {
+        Self { pos, axis }
+    }
+
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// Where `point` ends up after folding. Points already on the near side
+    /// of the fold don't move.
+    pub fn fold(&self, point: &Point) -> Point {
+        if self.axis == Axis::X {
+            if point.0 <= self.pos {
+                *point
+            } else {
+                (point.0 - 2 * (point.0 - self.pos), point.1)
+            }
+        } else {
+            if point.1 <= self.pos {
+                *point
+            } else {
+                (point.0, point.1 - 2 * (point.1 - self.pos))
+            }
+        }
+    }
+}
+
+impl FromStr for Fold {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_fold(InputLine::new(1, s))
+    }
+}
+
+impl fmt::Display for Fold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "fold along {}={}", self.axis, self.pos)
+    }
+}
+
+/// The dots on the first page of the thermal camera manual and the folds to
+/// make in it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Manual {
+    dots: Vec<Point>,
+    folds: Vec<Fold>,
+}
+
+impl Manual {
+    pub fn new(dots: Vec<Point>, folds: Vec<Fold>) -> Self {
+        Self { dots, folds }
+    }
+
+    pub fn dots(&self) -> &[Point] {
+        &self.dots
+    }
+
+    pub fn folds(&self) -> &[Fold] {
+        &self.folds
+    }
+}
+
+impl FromStr for Manual {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl fmt::Display for Manual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (x, y) in self.dots.iter() {
+            writeln!(f, "{},{}", x, y)?;
+        }
+        writeln!(f)?;
+        for fold in self.folds.iter() {
+            writeln!(f, "{}", fold)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_value(line: InputLine, value: Option<&str>) -> Result<usize> {
+    let value = value.ok_or_else(|| {
+        line.error_at_end("Expected a value.")
+            .with_expected("a non-negative integer")
+    })?;
+    let parsed = value.parse::<usize>().map_err(|e| {
+        line.error_at(value, "Unable to parse integer.")
+            .with_expected("a non-negative integer")
+            .with_source(e)
+    })?;
+    Ok(parsed)
+}
+
+fn parse_fold(line: InputLine) -> Result<Fold> {
+    let spec = line.text().strip_prefix("fold along ").ok_or_else(|| {
+        line.error_at(line.text(), "Expected 'fold along '.")
+            .with_expected("a fold like 'fold along y=7'")
+    })?;
+    let mut split = spec.split('=');
+    let axis = match split.next() {
+        Some("x") => Axis::X,
+        Some("y") => Axis::Y,
+        _ => {
+            return Err(line
+                .error_at(spec, "Expected axis 'x' or 'y'.")
+                .with_expected("'x=<position>' or 'y=<position>'")
+                .into())
+        }
+    };
+    let position = parse_value(line, split.next())?;
+
+    Ok(Fold::new(position, axis))
+}
+
+fn parse_input(data: &str) -> Result<Manual> {
+    let mut line_iter = input::lines(data);
+
+    let mut points = Vec::new();
+    for line in line_iter.by_ref() {
+        if line.text().is_empty() {
+            break;
+        }
+
+        let mut split = line.text().split(',');
+        let x = parse_value(line, split.next())?;
+        let y = parse_value(line, split.next())?;
+
+        points.push((x, y));
+    }
+
+    let folds = line_iter.map(parse_fold).collect::<Result<_>>()?;
+
+    Ok(Manual::new(points, folds))
+}
+
+fn fold_points(points: &[Point], folds: &[Fold]) -> Vec<Point> {
+    let mut folded_points = points.to_vec();
+
+    for fold in folds {
+        folded_points = folded_points
+            .iter()
+            .map(|p| fold.fold(p))
+            .collect::<Vec<_>>();
+    }
+
+    folded_points
+}
+
+fn count_unique_points(points: &[Point]) -> usize {
+    let mut set = HashSet::new();
+    for point in points {
+        set.insert(*point);
+    }
+    set.len()
+}
+
+fn render_points(points: &[Point]) -> Result<String> {
+    let mut unique_points = HashSet::new();
+    for point in points {
+        unique_points.insert(point);
+    }
+
+    let max_x = points
+        .iter()
+        .max_by(|&p1, &p2| p1.0.cmp(&p2.0))
+        .map(|p| p.0 + 1)
+        .ok_or_else(|| Error::unsolvable("No points to render."))?;
+    let max_y = points
+        .iter()
+        .max_by(|&p1, &p2| p1.1.cmp(&p2.1))
+        .map(|p| p.1 + 1)
+        .ok_or_else(|| Error::unsolvable("No points to render."))?;
+
+    let mut rendered = String::with_capacity((max_x + 1) * max_y);
+
+    for y in 0..max_y {
+        if y > 0 {
+            rendered.push('\n');
+        }
+        for x in 0..max_x {
+            if unique_points.contains(&(x, y)) {
+                rendered.push('#');
+            } else {
+                rendered.push(' ');
+            }
+        }
+    }
+
+    Ok(rendered)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Manual;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let (points, folds) = (input.dots(), input.folds());
+        let first_fold = folds
+            .get(..1)
+            .ok_or_else(|| Error::unsolvable("No folds found in input."))?;
+        let folded_points = fold_points(points, first_fold);
+        Ok(count_unique_points(&folded_points))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let (points, folds) = (input.dots(), input.folds());
+        let folded_points = fold_points(points, folds);
+        render_points(&folded_points)
+    }
+
+    #[cfg(feature = "json")]
+    fn extras(&self, input: &Self::Input) -> Result<json::Value> {
+        let (points, folds) = (input.dots(), input.folds());
+        let mut folded_points = fold_points(points, folds);
+        folded_points.sort_unstable();
+        folded_points.dedup();
+
+        let points = folded_points
+            .iter()
+            .map(|&(x, y)| vec![x, y])
+            .collect::<Vec<_>>();
+        Ok(json::Value::object().with("folded_points", points))
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config;
+use crate::error::{Error, ParseError, Result};
+use crate::input::{self, InputLine};
+use crate::memo::{FnvBuildHasher, Memo};
+use crate::Solution;
+
+fn add_counts(totals: &mut HashMap<char, usize>, counts: &HashMap<char, usize>) -> Result<()> {
+    for (k, v) in counts.iter() {
+        let total = totals.entry(*k).or_default();
+        *total = total
+            .checked_add(*v)
+            .ok_or_else(|| Error::unsolvable(format!("Too many '{}' elements to count.", k)))?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+struct Polymeriser {
+    mapping: HashMap<(char, char), char>,
+    cache: Memo<(char, char, usize), HashMap<char, usize>, FnvBuildHasher>,
+}
+
+impl Polymeriser {
+    fn new(mapping: HashMap<(char, char), char>) -> Self {
+        Self {
+            mapping,
+            cache: Memo::with_hasher("day14 pair counts", FnvBuildHasher::default()),
+        }
+    }
+
+    fn polymerise(
+        &mut self,
+        template: &str,
+        num_iterations: usize,
+    ) -> Result<HashMap<char, usize>> {
+        if template.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut char_iter = template.chars();
+        let mut char_counts = HashMap::new();
+
+        let mut first = char_iter.next().unwrap();
+        char_counts.insert(first, 1);
+
+        for second in char_iter {
+            self.polymerise_pair((first, second), num_iterations, &mut char_counts)?;
+            first = second;
+        }
+
+        Ok(char_counts)
+    }
+
+    fn polymerise_pair(
+        &mut self,
+        pair: (char, char),
+        num_iterations: usize,
+        totals: &mut HashMap<char, usize>,
+    ) -> Result<()> {
+        let key = (pair.0, pair.1, num_iterations);
+        if let Some(counts) = self.cache.get(&key) {
+            return add_counts(totals, counts);
+        }
+
+        let mut counts = HashMap::new();
+
+        if num_iterations == 0 {
+            counts.insert(pair.1, 1);
+        } else {
+            let new_char = self.mapping.get(&pair).copied().ok_or_else(|| {
+                Error::unsolvable(format!("Unable to find pair ({}, {}).", pair.0, pair.1))
+            })?;
+
+            self.polymerise_pair((pair.0, new_char), num_iterations - 1, &mut counts)?;
+            self.polymerise_pair((new_char, pair.1), num_iterations - 1, &mut counts)?;
+        }
+
+        add_counts(totals, &counts)?;
+        self.cache.insert(key, counts);
+        Ok(())
+    }
+}
+
+fn parse_line(line: InputLine) -> Result<((char, char), char)> {
+    let mut split_iter = line.text().split(" -> ");
+    let element_pair = split_iter.next().unwrap_or_default();
+    let mut pair_chars = element_pair.chars();
+    let (first_element, second_element) = match (pair_chars.next(), pair_chars.next()) {
+        (Some(first), Some(second)) => (first, second),
+        _ => return Err(line.error("Expected an element pair.").into()),
+    };
+
+    let result = split_iter
+        .next()
+        .ok_or_else(|| line.error_at_end("Expected ' -> ' followed by an element."))?;
+    let result_element = result
+        .chars()
+        .next()
+        .ok_or_else(|| line.error_at(result, "Expected an element."))?;
+
+    Ok(((first_element, second_element), result_element))
+}
+
+/// The pair insertion rules, mapping each pair of elements to the element
+/// inserted between them.
+pub type Mapping = HashMap<(char, char), char>;
+
+/// The polymer template and pair insertion rules from the submarine's manual.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PolymerFormula {
+    template: String,
+    rules: Mapping,
+}
+
+impl PolymerFormula {
+    pub fn new(template: String, rules: Mapping) -> Self {
+        Self { template, rules }
+    }
+
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    pub fn rules(&self) -> &Mapping {
+        &self.rules
+    }
+}
+
+impl FromStr for PolymerFormula {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl fmt::Display for PolymerFormula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.template)?;
+        writeln!(f)?;
+        let mut rules = self.rules.iter().collect::<Vec<_>>();
+        rules.sort_unstable();
+        for ((first, second), element) in rules {
+            writeln!(f, "{}{} -> {}", first, second, element)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_input(data: &str) -> Result<PolymerFormula> {
+    let mut lines_iter = input::lines(data);
+    let template = lines_iter
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "Input file is empty."))?
+        .text();
+
+    lines_iter.next();
+
+    let mut element_mapping = HashMap::new();
+
+    for map in lines_iter {
+        let (pair, new_element) = parse_line(map)?;
+        element_mapping.insert(pair, new_element);
+    }
+
+    Ok(PolymerFormula::new(template.to_string(), element_mapping))
+}
+
+fn compute_min_max_diff(counts: &HashMap<char, usize>) -> Result<usize> {
+    let min_count = counts
+        .iter()
+        .min_by(|a, b| a.1.cmp(b.1))
+        .map(|(_, v)| *v)
+        .ok_or_else(|| Error::unsolvable("Unable to derive minimum."))?;
+    let max_count = counts
+        .iter()
+        .max_by(|a, b| a.1.cmp(b.1))
+        .map(|(_, v)| *v)
+        .ok_or_else(|| Error::unsolvable("Unable to derive maximum."))?;
+
+    Ok(max_count - min_count)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day14 {
+    part1_steps: usize,
+    part2_steps: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self {
+            part1_steps: 10,
+            part2_steps: 40,
+        }
+    }
+}
+
+impl Solution for Day14 {
+    type Input = PolymerFormula;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let mut polymeriser = Polymeriser::new(input.rules().clone());
+        let counts = polymeriser.polymerise(input.template(), self.part1_steps)?;
+        compute_min_max_diff(&counts)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let mut polymeriser = Polymeriser::new(input.rules().clone());
+        let counts = polymeriser.polymerise(input.template(), self.part2_steps)?;
+        compute_min_max_diff(&counts)
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "part1_steps" => self.part1_steps = config::parse_value(key, value)?,
+            "part2_steps" => self.part2_steps = config::parse_value(key, value)?,
+            _ => return Err(config::unknown_key(key)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{self, Rng};
+
+    /// Builds the whole polymer, which is only feasible for a few steps.
+    fn polymerise_naively(template: &str, mapping: &Mapping, steps: usize) -> String {
+        let mut polymer = template.chars().collect::<Vec<_>>();
+        for _ in 0..steps {
+            let mut next = vec![polymer[0]];
+            for pair in polymer.windows(2) {
+                next.push(mapping[&(pair[0], pair[1])]);
+                next.push(pair[1]);
+            }
+            polymer = next;
+        }
+        polymer.into_iter().collect()
+    }
+
+    #[test]
+    fn test_polymerise_matches_naive() {
+        let generator = generate::generator(14).unwrap();
+        for seed in 0..50 {
+            let mut rng = Rng::new(seed);
+            let size = rng.range(2, 8) as usize;
+            let formula = parse_input(&(generator.generate)(&mut rng, size)).unwrap();
+            let (template, mapping) = (formula.template(), formula.rules());
+
+            for steps in 0..=8 {
+                let mut expected = HashMap::new();
+                for c in polymerise_naively(template, mapping, steps).chars() {
+                    *expected.entry(c).or_default() += 1;
+                }
+                let mut polymeriser = Polymeriser::new(mapping.clone());
+                let counts = polymeriser.polymerise(template, steps).unwrap();
+                assert_eq!(expected, counts, "seed {}, {} steps", seed, steps);
+            }
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config;
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::search;
+use crate::viz::{self, Frame};
+use crate::Solution;
+
+/// The risk level of each position in the cave, one digit per position.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Map {
+    risks: Grid<u32>,
+}
+
+impl Map {
+    pub fn new(risks: Grid<u32>) -> Self {
+        Self { risks }
+    }
+
+    pub fn risks(&self) -> &Grid<u32> {
+        &self.risks
+    }
+
+    /// The full map: this one repeated `tile_factor` times in each
+    /// direction, with risk levels rising by one per tile and wrapping from
+    /// 9 back to 1.
+    pub fn tiled(&self, tile_factor: usize) -> Self {
+        let small = &self.risks;
+        let (width, height) = (small.width(), small.height());
+
+        let risks = Grid::from_fn(width * tile_factor, height * tile_factor, |row, col| {
+            let offset = row / height + col / width;
+            roll_risk_value(small[(row % height, col % width)], offset as u32)
+        });
+
+        Self::new(risks)
+    }
+
+    /// The lowest total risk of any path from `start` to the bottom right
+    /// corner.
+    pub fn find_path(&self, start: (usize, usize)) -> Result<u32> {
+        let target = (self.risks.height() - 1, self.risks.width() - 1);
+        let (width, height) = (self.risks.width(), self.risks.height());
+        let mut explored = viz::recording().then(|| Grid::new(width, height, false));
+        let mut expanded = 0;
+        let frame_interval = (self.risks.len() / 100).max(1);
+
+        let successors = |&(row, col): &(usize, usize)| {
+            if let Some(explored) = explored.as_mut() {
+                explored[(row, col)] = true;
+                expanded += 1;
+                if expanded % frame_interval == 0 {
+                    viz::frame(|| self.frame(explored, &[]));
+                }
+            }
+            self.risks.neighbours4(row, col).map(|p| (p, self.risks[p]))
+        };
+
+        // Every step costs at least the lowest risk, so the Manhattan distance
+        // scaled by it never overestimates the remaining risk. With a zero in
+        // the map there is no useful bound.
+        let min_risk = self.risks.iter().copied().min().unwrap_or_default();
+        let result = if min_risk == 0 {
+            search::dijkstra(start, successors, |&p| p == target)
+        } else {
+            search::astar(
+                start,
+                successors,
+                |&(row, col)| (target.0 - row + target.1 - col) as u32 * min_risk,
+                |&p| p == target,
+            )
+        };
+
+        if let (Some(explored), Some(path)) = (explored.as_ref(), result.path.as_ref()) {
+            viz::frame(|| self.frame(explored, &path.nodes));
+        }
+
+        result
+            .path
+            .map(|path| path.cost)
+            .ok_or_else(|| Error::unsolvable("No path to the bottom right corner."))
+    }
+
+    /// The path is brightest, then the positions the search expanded, with
+    /// each shaded by its risk.
+    fn frame(&self, explored: &Grid<bool>, path: &[(usize, usize)]) -> Frame {
+        let mut levels = Grid::from_fn(self.risks.width(), self.risks.height(), |row, col| {
+            let risk = self.risks[(row, col)] as u8;
+            if explored[(row, col)] {
+                140 + risk * 10
+            } else {
+                risk * 12
+            }
+        });
+        for &position in path {
+            levels[position] = 255;
+        }
+        Frame::new(levels)
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let risks = Grid::parse(s, "a risk level digit", |c| c.to_digit(10))?;
+        Ok(Self::new(risks))
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.risks)
+    }
+}
+
+fn roll_risk_value(init: u32, offset: u32) -> u32 {
+    let rolled = init + offset;
+    if rolled > 9 {
+        // A large tile factor can wrap more than once.
+        (rolled - 1) % 9 + 1
+    } else {
+        rolled
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day15 {
+    tile_factor: usize,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self { tile_factor: 5 }
+    }
+}
+
+impl Solution for Day15 {
+    type Input = (Map, Map);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let small_map = input.parse::<Map>()?;
+        let large_map = small_map.tiled(self.tile_factor);
+        Ok((small_map, large_map))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let (small_map, _) = input;
+        small_map.find_path((0, 0))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let (_, large_map) = input;
+        large_map.find_path((0, 0))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "tile_factor" => {
+                self.tile_factor = config::parse_value(key, value)?;
+                if self.tile_factor == 0 {
+                    return Err(Error::invalid_config("'tile_factor' must be at least 1."));
+                }
+            }
+            _ => return Err(config::unknown_key(key)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_risk_matches_dijkstra() {
+        let map = "100500\n900555\n599959\n995909\n959099\n090000\n"
+            .parse::<Map>()
+            .unwrap();
+        let risks = map.risks();
+        let target = (risks.height() - 1, risks.width() - 1);
+        let expected = search::dijkstra(
+            (0, 0),
+            |&(row, col)| risks.neighbours4(row, col).map(|p| (p, risks[p])),
+            |&p| p == target,
+        );
+        assert_eq!(Some(23), expected.path.map(|path| path.cost));
+        assert_eq!(23, map.find_path((0, 0)).unwrap());
+    }
+}
@@ -1,11 +1,50 @@
-use std::env;
-use std::error;
-use std::fs;
+use std::fmt;
+use std::str::FromStr;
 
-use aoc2021::error::Error;
+use crate::error::{Error, ParseError, Result};
+use crate::Solution;
 
+/// A BITS transmission, written as hexadecimal.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Transmission {
+    bytes: Vec<u8>,
+}
+
+impl Transmission {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn parser(&self) -> Parser {
+        Parser::new(self.bytes.clone())
+    }
+}
+
+impl FromStr for Transmission {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Self::new(parse_input(s.trim_end())?))
+    }
+}
+
+impl fmt::Display for Transmission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.bytes.iter() {
+            write!(f, "{:02X}", byte)?;
+        }
+        writeln!(f)
+    }
+}
+
+/// Decodes the outermost packet of a transmission, evaluating it as it goes
+/// and summing the version numbers of every packet it contains.
 #[derive(Debug, Default, Clone)]
-struct Parser {
+pub struct Parser {
     data: Vec<u8>,
     current: usize,
     version_sum: usize,
@@ -13,7 +52,7 @@ struct Parser {
 }
 
 impl Parser {
-    fn new(data: Vec<u8>) -> Self {
+    pub fn new(data: Vec<u8>) -> Self {
         Self {
             data,
             current: 0,
@@ -22,42 +61,53 @@ impl Parser {
         }
     }
 
-    fn parse(&mut self) -> usize {
-        self.parse_packet();
-        self.stack.last().copied().expect("Stack is empty.")
+    /// Decodes the next packet and returns its value.
+    pub fn parse(&mut self) -> Result<usize> {
+        self.parse_packet()?;
+        self.stack
+            .last()
+            .copied()
+            .ok_or_else(|| Error::invalid_state("Stack is empty."))
+    }
+
+    /// The sum of the version numbers of every packet decoded so far.
+    pub fn version_sum(&self) -> usize {
+        self.version_sum
     }
 
-    fn parse_packet(&mut self) {
-        let version = self.parse_bits(3);
+    fn parse_packet(&mut self) -> Result<()> {
+        let version = self.parse_bits(3)?;
         self.version_sum += version;
-        let packet_type = self.parse_bits(3);
+        let packet_type = self.parse_bits(3)?;
 
         if packet_type == 4 {
-            let literal = self.parse_literal();
+            let literal = self.parse_literal()?;
             self.stack.push(literal);
         } else {
             let stack_size = self.stack.len();
-            let length_type = self.advance().expect("Insufficient bits found for packet.");
+            let length_type = self
+                .advance()
+                .ok_or_else(|| Error::unsolvable("Insufficient bits found for packet."))?;
             if length_type {
-                let num_packets = self.parse_bits(11);
+                let num_packets = self.parse_bits(11)?;
                 for _ in 0..num_packets {
-                    self.parse_packet();
+                    self.parse_packet()?;
                 }
             } else {
-                let length = self.parse_bits(15);
+                let length = self.parse_bits(15)?;
                 let current_bits = self.current;
                 let target_bits = current_bits + length;
 
                 while self.current < target_bits {
-                    self.parse_packet();
+                    self.parse_packet()?;
                 }
             }
 
             macro_rules! cumulative_op {
                 ($op:expr) => {{
-                    let mut acc = self.stack.pop().expect("Stack is empty.");
+                    let mut acc = self.pop_stack()?;
                     while self.stack.len() > stack_size {
-                        acc = $op(acc, self.stack.pop().expect("Stack is empty."));
+                        acc = $op(acc, self.pop_stack()?);
                     }
                     self.stack.push(acc);
                 }};
@@ -65,8 +115,8 @@ impl Parser {
 
             macro_rules! binary_op {
                 ($op:expr) => {{
-                    let second = self.stack.pop().expect("Stack is empty.");
-                    let first = self.stack.pop().expect("Stack is empty.");
+                    let second = self.pop_stack()?;
+                    let first = self.pop_stack()?;
                     self.stack.push($op(first, second));
                 }};
             }
@@ -91,17 +141,30 @@ impl Parser {
                 5 => binary_op!(|a, b| if a > b { 1 } else { 0 }),
                 6 => binary_op!(|a, b| if a < b { 1 } else { 0 }),
                 7 => binary_op!(|a, b| if a == b { 1 } else { 0 }),
-                _ => panic!("Unknown packet type."),
+                _ => {
+                    let message = format!("Unknown packet type {}.", packet_type);
+                    return Err(Error::unsolvable(message));
+                }
+            }
+            if stack_size + 1 != self.stack.len() {
+                return Err(Error::invalid_state("Operator left an unbalanced stack."));
             }
-            assert_eq!(stack_size + 1, self.stack.len());
         }
+
+        Ok(())
+    }
+
+    fn pop_stack(&mut self) -> Result<usize> {
+        self.stack
+            .pop()
+            .ok_or_else(|| Error::invalid_state("Stack is empty."))
     }
 
-    fn parse_literal(&mut self) -> usize {
+    fn parse_literal(&mut self) -> Result<usize> {
         let mut bit_groups = Vec::new();
 
         loop {
-            let group = self.parse_bits(5);
+            let group = self.parse_bits(5)?;
             bit_groups.push(group);
             if (group & 0b10000) == 0 {
                 break;
@@ -114,20 +177,23 @@ impl Parser {
             value |= *group & 0b1111;
         }
 
-        value
+        Ok(value)
     }
 
-    fn parse_bits(&mut self, num_bits: u32) -> usize {
+    fn parse_bits(&mut self, num_bits: u32) -> Result<usize> {
         assert!(num_bits <= usize::BITS);
         let mut value = 0;
 
         for i in (0..num_bits).rev() {
-            if self.advance().expect("Insufficient bits found for value.") {
+            let bit = self
+                .advance()
+                .ok_or_else(|| Error::unsolvable("Insufficient bits found for value."))?;
+            if bit {
                 value |= 1 << i;
             }
         }
 
-        value
+        Ok(value)
     }
 
     fn current_bit(&self) -> bool {
@@ -146,55 +212,50 @@ impl Parser {
     }
 }
 
-fn print_bits(data: &[u8]) {
-    for d in data {
-        let s = &format!("{:#010b}", d)[2..];
-        print!("{} ", s)
-    }
-    println!()
-}
-
-fn parse_input(data: &str) -> Result<Vec<u8>, Box<dyn error::Error>> {
+fn parse_input(data: &str) -> Result<Vec<u8>> {
     let mut parsed_hex = Vec::new();
     for i in (0..data.len()).step_by(2) {
-        if !data.is_char_boundary(i + 2) {
-            return Err(Box::new(Error("Unable to parse input.".to_string())));
+        if i + 2 > data.len() || !data.is_char_boundary(i) || !data.is_char_boundary(i + 2) {
+            let column = data[..i.min(data.len())].chars().count() + 1;
+            return Err(ParseError::new(1, column, "Expected a pair of hex digits.").into());
         }
 
         let hex_string = &data[i..i + 2];
-        let value = u8::from_str_radix(hex_string, 16)?;
+        let value = u8::from_str_radix(hex_string, 16).map_err(|e| {
+            ParseError::new(
+                1,
+                data[..i].chars().count() + 1,
+                "Unable to parse hex value.",
+            )
+            .with_source(e)
+        })?;
         parsed_hex.push(value);
     }
 
     Ok(parsed_hex)
 }
 
-fn main() -> Result<(), Box<dyn error::Error>> {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        return Err(Box::new(Error(format!(
-            "Usage: {} <input data path>",
-            args[0]
-        ))));
-    }
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day16;
 
-    let path = &args[1];
-    let file_contents =
-        fs::read_to_string(path).map_err(|e| format!("Error reading input data: {}.", e))?;
+impl Solution for Day16 {
+    type Input = Transmission;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let bytes = parse_input(&file_contents)?;
-    if cfg!(debug_assertions) {
-        print_bits(&bytes);
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
     }
 
-    let mut parser = Parser::new(bytes);
-    let result = parser.parse();
-
-    println!("Part one: {}", parser.version_sum);
-    println!("Part two: {}", result);
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let mut parser = input.parser();
+        parser.parse()?;
+        Ok(parser.version_sum())
+    }
 
-    Ok(())
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        input.parser().parse()
+    }
 }
 
 #[cfg(test)]
@@ -212,8 +273,8 @@ mod tests {
     fn test_parse_header() {
         let bytes = vec![56];
         let mut parser = Parser::new(bytes);
-        let version = parser.parse_bits(3);
-        let type_ = parser.parse_bits(3);
+        let version = parser.parse_bits(3).unwrap();
+        let type_ = parser.parse_bits(3).unwrap();
 
         assert_eq!(1, version);
         assert_eq!(6, type_);
@@ -223,7 +284,7 @@ mod tests {
     fn test_parse_packet_literal() {
         let bytes = vec![0xd2, 0xfe, 0x28];
         let mut parser = Parser::new(bytes);
-        let literal = parser.parse();
+        let literal = parser.parse().unwrap();
         assert_eq!(2021, literal);
         assert_eq!(1, parser.stack.len());
     }
@@ -232,7 +293,7 @@ mod tests {
     fn test_parse_packet_operator_sum() {
         let bytes = vec![0xc2, 0x00, 0xb4, 0x0a, 0x82];
         let mut parser = Parser::new(bytes);
-        let result = parser.parse();
+        let result = parser.parse().unwrap();
         assert_eq!(3, result);
         assert_eq!(1, parser.stack.len());
     }
@@ -241,7 +302,7 @@ mod tests {
     fn test_parse_packet_operator_product() {
         let bytes = vec![0x4, 0x0, 0x5a, 0xc3, 0x38, 0x90];
         let mut parser = Parser::new(bytes);
-        let result = parser.parse();
+        let result = parser.parse().unwrap();
         assert_eq!(54, result);
         assert_eq!(1, parser.stack.len());
     }
@@ -250,7 +311,7 @@ mod tests {
     fn test_parse_packet_operator_min() {
         let bytes = vec![0x88, 0x0, 0x86, 0xc3, 0xe8, 0x81, 0x12];
         let mut parser = Parser::new(bytes);
-        let result = parser.parse();
+        let result = parser.parse().unwrap();
         assert_eq!(7, result);
         assert_eq!(1, parser.stack.len());
     }
@@ -259,7 +320,7 @@ mod tests {
     fn test_parse_packet_operator_max() {
         let bytes = vec![0xce, 0x0, 0xc4, 0x3d, 0x88, 0x11, 0x20];
         let mut parser = Parser::new(bytes);
-        let result = parser.parse();
+        let result = parser.parse().unwrap();
         assert_eq!(9, result);
         assert_eq!(1, parser.stack.len());
     }
@@ -268,7 +329,7 @@ mod tests {
     fn test_parse_packet_operator_less() {
         let bytes = vec![0xd8, 0x0, 0x5a, 0xc2, 0xa8, 0xf0];
         let mut parser = Parser::new(bytes);
-        let result = parser.parse();
+        let result = parser.parse().unwrap();
         assert_eq!(1, result);
         assert_eq!(1, parser.stack.len());
     }
@@ -277,7 +338,7 @@ mod tests {
     fn test_parse_packet_operator_greater() {
         let bytes = vec![0xf6, 0x0, 0xbc, 0x2d, 0x8f];
         let mut parser = Parser::new(bytes);
-        let result = parser.parse();
+        let result = parser.parse().unwrap();
         assert_eq!(0, result);
         assert_eq!(1, parser.stack.len());
     }
@@ -286,7 +347,7 @@ mod tests {
     fn test_parse_packet_operator_equal() {
         let bytes = vec![0x9c, 0x0, 0x5a, 0xc2, 0xf8, 0xf0];
         let mut parser = Parser::new(bytes);
-        let result = parser.parse();
+        let result = parser.parse().unwrap();
         assert_eq!(0, result);
         assert_eq!(1, parser.stack.len());
     }
@@ -297,7 +358,7 @@ mod tests {
             0x9c, 0x1, 0x41, 0x8, 0x2, 0x50, 0x32, 0xf, 0x18, 0x2, 0x10, 0x4a, 0x8,
         ];
         let mut parser = Parser::new(bytes);
-        let result = parser.parse();
+        let result = parser.parse().unwrap();
         assert_eq!(1, result);
         assert_eq!(1, parser.stack.len());
     }
@@ -1,19 +1,24 @@
-use std::env;
-use std::error;
-use std::fs;
+use std::fmt;
+use std::str::FromStr;
 
-use aoc2021::error::Error;
+use crate::error::{Error, Result};
+use crate::input::InputLine;
+use crate::pool;
+use crate::Solution;
 
-type Point = (i32, i32);
+pub type Point = (i32, i32);
 
-#[derive(Debug, Default, Copy, Clone)]
-struct Rectangle {
+/// The probe's target area, written as `target area: x=20..30, y=-10..-5`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rectangle {
     x_bounds: (i32, i32),
     y_bounds: (i32, i32),
 }
 
 impl Rectangle {
-    fn new(point0: Point, point1: Point) -> Self {
+    /// The rectangle with `point0` and `point1` at opposite corners, both
+    /// inclusive.
+    pub fn new(point0: Point, point1: Point) -> Self {
         let x_bounds = if point0.0 < point1.0 {
             (point0.0, point1.0)
         } else {
@@ -27,6 +32,14 @@ impl Rectangle {
         Self { x_bounds, y_bounds }
     }
 
+    pub fn x_bounds(&self) -> (i32, i32) {
+        self.x_bounds
+    }
+
+    pub fn y_bounds(&self) -> (i32, i32) {
+        self.y_bounds
+    }
+
     fn point_displacement(&self, point: Point) -> Point {
         (
             if point.0 < self.x_bounds.0 {
@@ -47,6 +60,24 @@ impl Rectangle {
     }
 }
 
+impl FromStr for Rectangle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s.trim_end())
+    }
+}
+
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "target area: x={}..{}, y={}..{}",
+            self.x_bounds.0, self.x_bounds.1, self.y_bounds.0, self.y_bounds.1
+        )
+    }
+}
+
 #[derive(Debug, Default, Copy, Clone)]
 struct Probe {
     position: Point,
@@ -97,10 +128,11 @@ fn simulate_probe(target: &Rectangle, init_velocity: Point, max_steps: usize) ->
     None
 }
 
-fn parse_input(data: &str) -> Result<Rectangle, Box<dyn error::Error>> {
+fn parse_input(data: &str) -> Result<Rectangle> {
+    let line = InputLine::new(1, data);
     let stripped_data = data
         .strip_prefix("target area: ")
-        .ok_or_else(|| Error("Unable to parse input.".to_string()))?;
+        .ok_or_else(|| line.error("Expected 'target area: ' prefix."))?;
 
     let mut values = [0; 4];
     let prefixes = ["x=", "y="];
@@ -109,17 +141,19 @@ fn parse_input(data: &str) -> Result<Rectangle, Box<dyn error::Error>> {
     for (i, prefix) in prefixes.iter().enumerate() {
         let coord_data = split_iter
             .next()
-            .ok_or(Error("Unable to parse input.".to_string()))?;
+            .ok_or_else(|| line.error_at_end(format!("Expected '{}' range.", prefix)))?;
         let mut coord_value_iter = coord_data
             .strip_prefix(*prefix)
             .map(|s| s.split(".."))
-            .ok_or_else(|| Error("Unable to parse input.".to_string()))?;
+            .ok_or_else(|| line.error_at(coord_data, format!("Expected '{}' prefix.", prefix)))?;
         for j in 0..2 {
             let coord_value = coord_value_iter
                 .next()
-                .and_then(|s| s.parse::<i32>().ok())
-                .ok_or_else(|| Error("Unable to parse value.".to_string()))?;
-            values[i * 2 + j] = coord_value;
+                .ok_or_else(|| line.error_at_end("Expected a range 'a..b'."))?;
+            values[i * 2 + j] = coord_value.parse::<i32>().map_err(|e| {
+                line.error_at(coord_value, "Unable to parse value.")
+                    .with_source(e)
+            })?;
         }
     }
 
@@ -129,39 +163,38 @@ fn parse_input(data: &str) -> Result<Rectangle, Box<dyn error::Error>> {
     ))
 }
 
-fn main() -> Result<(), Box<dyn error::Error>> {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() != 2 {
-        return Err(Box::new(Error(format!(
-            "Usage: {} <input data path>",
-            args[0]
-        ))));
-    }
+fn find_trajectory_heights(target_area: &Rectangle) -> Vec<i32> {
+    let max_x_vel = target_area.x_bounds.1 * 2;
+    let min_y_vel = target_area.y_bounds.1 * 2;
+    let x_vels = (0..max_x_vel).collect::<Vec<_>>();
+    pool::map(&x_vels, |&x_vel| {
+        (min_y_vel..1000)
+            .filter_map(|y_vel| simulate_probe(target_area, (x_vel, y_vel), 10000))
+            .collect::<Vec<_>>()
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
 
-    let path = &args[1];
-    let file_contents =
-        fs::read_to_string(path).map_err(|e| format!("Error reading input data: {}.", e))?;
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day17;
 
-    let target_area = parse_input(&file_contents)?;
+impl Solution for Day17 {
+    type Input = Rectangle;
+    type Part1 = i32;
+    type Part2 = usize;
 
-    let mut max_height = 0;
-    let mut velocity_count = 0;
-    let max_x_vel = target_area.x_bounds.1 * 2;
-    let min_y_vel = target_area.y_bounds.1 * 2;
-    for x_vel in 0..max_x_vel {
-        for y_vel in min_y_vel..1000 {
-            if let Some(height) = simulate_probe(&target_area, (x_vel, y_vel), 10000) {
-                velocity_count += 1;
-                if height > max_height {
-                    max_height = height;
-                }
-            }
-        }
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
     }
 
-    println!("Part one: {}", max_height);
-    println!("Part two: {}", velocity_count);
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let heights = find_trajectory_heights(input);
+        Ok(heights.iter().copied().fold(0, i32::max))
+    }
 
-    Ok(())
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        Ok(find_trajectory_heights(input).len())
+    }
 }
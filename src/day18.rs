@@ -1,67 +1,143 @@
-use std::env;
-use std::error;
 use std::fmt;
-use std::fs;
 use std::ops::Add;
+use std::str::FromStr;
+
+use crate::error::{Error, ParseError, Result};
+use crate::input::{self, InputLine};
+use crate::pool;
+use crate::Solution;
+
+pub type Pair = (Box<Number>, Box<Number>);
+
+/// What comes next in a pair that is still open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    First,
+    Comma,
+    Second,
+    Close,
+}
 
-use aoc2021::error::Error;
-
-type Pair = (Box<Number>, Box<Number>);
-
-#[derive(Debug, Default, Clone)]
-struct Parser {
-    source: String,
+#[derive(Debug, Clone)]
+struct Parser<'a> {
+    line: InputLine<'a>,
+    start: usize,
     current: usize,
-    depth: usize,
+    open: Vec<Expect>,
     stack: Vec<Number>,
 }
 
-impl Parser {
-    fn new(source: &str) -> Self {
+impl<'a> Parser<'a> {
+    fn new(line: InputLine<'a>) -> Self {
         Self {
-            source: source.to_string(),
+            line,
+            start: 0,
             current: 0,
-            depth: 0,
+            open: Vec::new(),
             stack: Vec::new(),
         }
     }
 
-    fn parse(&mut self) -> Result<Number, Box<dyn error::Error>> {
-        while let Some(c) = self.advance() {
+    fn parse(&mut self) -> Result<Number> {
+        while let Some(c) = self.peek() {
+            self.start = self.current;
+            self.advance();
             match c {
-                '[' => self.depth += 1,
+                '[' => {
+                    self.begin_element()?;
+                    self.open.push(Expect::First);
+                }
                 ']' => {
-                    if self.depth == 0 {
-                        return Err(Box::new(Error("Mismatched bracket in input.".to_string())));
+                    match self.open.pop() {
+                        Some(Expect::Close) => {}
+                        Some(_) => {
+                            return Err(self
+                                .error("Expected two elements in pair.")
+                                .with_expected("a pair like '[1,2]'")
+                                .into())
+                        }
+                        None => {
+                            return Err(self
+                                .error("Mismatched bracket in input.")
+                                .with_expected("a matching '['")
+                                .into())
+                        }
                     }
-                    self.depth -= 1;
                     let second = self.pop_stack()?;
                     let first = self.pop_stack()?;
                     self.stack
                         .push(Number::Pair((Box::new(first), Box::new(second))));
                 }
                 c if c.is_ascii_digit() => {
+                    self.begin_element()?;
                     let value = self.parse_regular_number(c)?;
                     self.stack.push(value);
                 }
-                ',' => {}
+                ',' => match self.open.last_mut() {
+                    Some(expect @ Expect::Comma) => *expect = Expect::Second,
+                    Some(Expect::Close) => {
+                        return Err(self
+                            .error("Unexpected ',' after the second element.")
+                            .with_expected("']'")
+                            .into())
+                    }
+                    Some(_) => {
+                        return Err(self
+                            .error("Expected an element before ','.")
+                            .with_expected("'[' or a digit")
+                            .into())
+                    }
+                    None => {
+                        return Err(self
+                            .error("Unexpected ',' outside a pair.")
+                            .with_expected("'['")
+                            .into())
+                    }
+                },
                 _ => {
-                    return Err(Box::new(Error(format!(
-                        "Unexpected character in input '{}'.",
-                        c
-                    ))));
+                    let message = format!("Unexpected character in input '{}'.", c);
+                    return Err(self
+                        .error(message)
+                        .with_expected("'[', ']', ',' or a digit")
+                        .into());
                 }
             }
         }
 
-        let result = self
-            .stack
-            .pop()
-            .ok_or_else(|| Error("Stack is empty.".to_string()))?;
+        self.start = self.current;
+        if !self.open.is_empty() || self.stack.len() > 1 {
+            return Err(self
+                .error("Unterminated pair in input.")
+                .with_expected("a closing ']'")
+                .into());
+        }
+
+        let result = self.stack.pop().ok_or_else(|| {
+            self.error("Expected a snailfish number.")
+                .with_expected("a pair like '[1,2]'")
+        })?;
         Ok(result)
     }
 
-    fn parse_regular_number(&mut self, begin: char) -> Result<Number, Box<dyn error::Error>> {
+    /// Records that an element starts at the current position in its pair.
+    fn begin_element(&mut self) -> Result<()> {
+        let (message, expected) = match self.open.last_mut() {
+            None => return Ok(()),
+            Some(expect @ Expect::First) => {
+                *expect = Expect::Comma;
+                return Ok(());
+            }
+            Some(expect @ Expect::Second) => {
+                *expect = Expect::Close;
+                return Ok(());
+            }
+            Some(Expect::Comma) => ("Expected ',' between the elements of a pair.", "','"),
+            Some(Expect::Close) => ("Too many elements in pair.", "']'"),
+        };
+        Err(self.error(message).with_expected(expected).into())
+    }
+
+    fn parse_regular_number(&mut self, begin: char) -> Result<Number> {
         let mut string = String::from(begin);
         while let Some(c) = self.peek() {
             if !c.is_ascii_digit() {
@@ -71,12 +147,16 @@ impl Parser {
             string.push(c);
         }
 
-        let value = string.parse::<usize>()?;
+        let value = string.parse::<usize>().map_err(|e| {
+            self.error("Unable to parse integer.")
+                .with_length(string.len())
+                .with_source(e)
+        })?;
         Ok(Number::Regular(value))
     }
 
     fn advance(&mut self) -> Option<char> {
-        if let Some(c) = self.source[self.current..].chars().next() {
+        if let Some(c) = self.peek() {
             self.current += c.len_utf8();
             Some(c)
         } else {
@@ -85,34 +165,77 @@ impl Parser {
     }
 
     fn peek(&self) -> Option<char> {
-        self.source[self.current..].chars().next()
+        self.line.text()[self.current..].chars().next()
     }
 
-    fn pop_stack(&mut self) -> Result<Number, Error> {
-        self.stack
-            .pop()
-            .ok_or_else(|| Error("Stack is empty.".to_string()))
+    fn pop_stack(&mut self) -> Result<Number> {
+        self.stack.pop().ok_or_else(|| {
+            self.error("Expected two elements in pair.")
+                .with_expected("a pair like '[1,2]'")
+                .into()
+        })
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        self.line.error_at(&self.line.text()[self.start..], message)
     }
 }
 
-fn parse_line(line: &str) -> Result<Number, Box<dyn error::Error>> {
+fn parse_line(line: InputLine) -> Result<Number> {
     let mut parser = Parser::new(line);
     parser.parse()
 }
 
-fn parse_input(data: &str) -> Result<Vec<Number>, Box<dyn error::Error>> {
-    data.lines()
-        .map(|s| parse_line(s))
-        .collect::<Result<Vec<_>, _>>()
+fn parse_input(data: &str) -> Result<Vec<Number>> {
+    input::lines(data).map(parse_line).collect()
+}
+
+/// The snailfish homework: a list of numbers, one per line.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Homework {
+    numbers: Vec<Number>,
+}
+
+impl Homework {
+    pub fn new(numbers: Vec<Number>) -> Self {
+        Self { numbers }
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+}
+
+impl FromStr for Homework {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Self::new(parse_input(s)?))
+    }
 }
 
+impl fmt::Display for Homework {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for number in self.numbers.iter() {
+            writeln!(f, "{}", number)?;
+        }
+        Ok(())
+    }
+}
+
+/// A snailfish number, written like `[[1,2],3]`. Adding two numbers with `+`
+/// pairs them up without reducing the result.
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum Number {
+pub enum Number {
     Regular(usize),
     Pair(Pair),
 }
 
 impl Number {
+    pub fn pair(left: Number, right: Number) -> Self {
+        Number::Pair((Box::new(left), Box::new(right)))
+    }
+
     fn add_left(&mut self, value: usize) {
         match self {
             Number::Regular(n) => *n += value,
@@ -128,6 +251,14 @@ impl Number {
     }
 }
 
+impl FromStr for Number {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_line(InputLine::new(1, s))
+    }
+}
+
 impl Add for Box<Number> {
     type Output = Box<Number>;
 
@@ -271,20 +402,17 @@ impl NumberVisitor for MagnitudeVisitor {
     fn visit(&mut self, number: &mut Number) -> Self::Output {
         match number {
             Number::Regular(n) => *n,
-            Number::Pair((l, r)) => {
-                let value = self.visit(l) * 3 + self.visit(r) * 2;
-                value
-            }
+            Number::Pair((l, r)) => self.visit(l) * 3 + self.visit(r) * 2,
         }
     }
 }
 
-fn sum(mut numbers: Vec<Number>) -> Result<Number, Box<dyn error::Error>> {
+fn sum(mut numbers: Vec<Number>) -> Result<Number> {
     numbers.reverse();
 
     let mut sum = numbers
         .pop()
-        .ok_or_else(|| Error("No numbers available.".to_string()))?;
+        .ok_or_else(|| Error::unsolvable("No numbers available."))?;
 
     while let Some(number) = numbers.pop() {
         sum = sum + number;
@@ -313,9 +441,10 @@ fn magnitude(value: &mut Number) -> usize {
     visitor.visit(value)
 }
 
-fn max_magnitude(numbers: Vec<Number>) -> Result<usize, Box<dyn error::Error>> {
-    let mut max_magnitude = 0;
-    for i in 0..numbers.len() {
+fn max_magnitude(numbers: Vec<Number>) -> Result<usize> {
+    let firsts = (0..numbers.len()).collect::<Vec<_>>();
+    let maxima = pool::map(&firsts, |&i| {
+        let mut max_magnitude = 0;
         for j in 0..numbers.len() {
             if i == j {
                 continue;
@@ -327,34 +456,33 @@ fn max_magnitude(numbers: Vec<Number>) -> Result<usize, Box<dyn error::Error>> {
                 max_magnitude = magnitude;
             }
         }
-    }
+        Ok(max_magnitude)
+    });
 
-    Ok(max_magnitude)
+    let maxima = maxima.into_iter().collect::<Result<Vec<_>>>()?;
+    Ok(maxima.into_iter().max().unwrap_or(0))
 }
 
-fn main() -> Result<(), Box<dyn error::Error>> {
-    let args: Vec<String> = env::args().collect();
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day18;
 
-    if args.len() != 2 {
-        return Err(Box::new(Error(format!(
-            "Usage: {} <input data path>",
-            args[0]
-        ))));
-    }
+impl Solution for Day18 {
+    type Input = Homework;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let path = &args[1];
-    let file_contents =
-        fs::read_to_string(path).map_err(|e| format!("Error reading input data: {}.", e))?;
-
-    let data = parse_input(&file_contents)?;
-
-    let mut sum_value = sum(data.clone())?;
-    let sum_magnitude = magnitude(&mut sum_value);
-    println!("Part one: {}", sum_magnitude);
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
 
-    println!("Part two: {}", max_magnitude(data)?);
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let mut sum_value = sum(input.numbers().to_vec())?;
+        Ok(magnitude(&mut sum_value))
+    }
 
-    Ok(())
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        max_magnitude(input.numbers().to_vec())
+    }
 }
 
 #[cfg(test)]
@@ -364,7 +492,7 @@ mod tests {
     #[test]
     fn test_parse_simple_pair() {
         let src = "[1,2]";
-        let mut parser = Parser::new(src);
+        let mut parser = Parser::new(InputLine::new(1, src));
         let expected = Number::Pair((Box::new(Number::Regular(1)), Box::new(Number::Regular(2))));
         let result = parser.parse().expect("Unable to parse input.");
         assert_eq!(expected, result);
@@ -373,7 +501,7 @@ mod tests {
     #[test]
     fn test_parse_nested_pair() {
         let src = "[[4,[6,7]],[[8,9],3]]";
-        let mut parser = Parser::new(src);
+        let mut parser = Parser::new(InputLine::new(1, src));
         let expected = Number::Pair((
             Box::new(Number::Pair((
                 Box::new(Number::Regular(4)),
@@ -394,6 +522,22 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn test_parse_bad_separator() {
+        for (src, column) in [
+            ("[[1,2],,3]", 8),
+            ("[[1,2]3]", 7),
+            ("[1,2],", 6),
+            ("[,1,2]", 2),
+        ] {
+            let mut parser = Parser::new(InputLine::new(1, src));
+            match parser.parse() {
+                Err(Error::Parse(e)) => assert_eq!((1, column), (e.line(), e.column()), "{}", src),
+                result => panic!("{}: {:?}", src, result),
+            }
+        }
+    }
+
     #[test]
     fn test_number_add_regular() {
         let first = Box::new(Number::Regular(1));
@@ -464,7 +608,8 @@ mod tests {
         ];
 
         for case in &cases {
-            let mut tree = parse_line(&case.input).expect("Unable to parse input.");
+            let mut tree =
+                parse_line(InputLine::new(1, &case.input)).expect("Unable to parse input.");
             let mut visitor = ExploderVisitor::new();
             visitor.visit(&mut tree);
             assert_eq!(case.output, format!("{}", tree));
@@ -474,7 +619,7 @@ mod tests {
     #[test]
     fn test_splitter_visitor() {
         let src = "[3,[11,12]]";
-        let mut tree = parse_line(src).expect("Unable to parse input.");
+        let mut tree = parse_line(InputLine::new(1, src)).expect("Unable to parse input.");
         let expected = "[3,[[5,6],12]]";
 
         let mut visitor = SplitterVisitor::new();
@@ -486,7 +631,7 @@ mod tests {
     #[test]
     fn test_magnitude_visitor() {
         let src = "[[1,2],[[3,4],5]]";
-        let mut tree = parse_line(src).expect("Unable to parse input.");
+        let mut tree = parse_line(InputLine::new(1, src)).expect("Unable to parse input.");
 
         let mut visitor = MagnitudeVisitor::new();
         let magnitude = visitor.visit(&mut tree);
@@ -494,7 +639,7 @@ mod tests {
         assert_eq!(143, magnitude);
 
         let src = "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]";
-        let mut tree = parse_line(src).expect("Unable to parse input.");
+        let mut tree = parse_line(InputLine::new(1, src)).expect("Unable to parse input.");
 
         let mut visitor = MagnitudeVisitor::new();
         let magnitude = visitor.visit(&mut tree);
@@ -505,14 +650,14 @@ mod tests {
     #[test]
     fn test_reduce() {
         let src = "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]";
-        let mut value = parse_line(src).expect("Unable to parse input.");
+        let mut value = parse_line(InputLine::new(1, src)).expect("Unable to parse input.");
         let expected = "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]";
 
         reduce(&mut value);
         assert_eq!(expected, format!("{}", value));
 
         let src = "[[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]],[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]]";
-        let mut value = parse_line(src).expect("Unable to parse input.");
+        let mut value = parse_line(InputLine::new(1, src)).expect("Unable to parse input.");
         let expected = "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]";
 
         reduce(&mut value);
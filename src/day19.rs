@@ -0,0 +1,362 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::config;
+use crate::error::{Error, ParseError, Result};
+use crate::geometry::{self, Matrix, Vector};
+use crate::input::{self, InputLine};
+use crate::instrument;
+#[cfg(feature = "json")]
+use crate::json;
+use crate::pool;
+use crate::Solution;
+
+const NDIMS: usize = 3;
+
+fn parse_scanner<'a, const N: usize>(
+    line_iter: &mut impl Iterator<Item = InputLine<'a>>,
+) -> Result<Scanner<N>> {
+    let mut points = Vec::new();
+
+    for line in line_iter.by_ref() {
+        if line.text().is_empty() {
+            break;
+        }
+        points.push(geometry::parse_vector(line)?);
+    }
+
+    Ok(Scanner::new(points))
+}
+
+fn parse_input<const N: usize>(data: &str) -> Result<Scanners<N>> {
+    let mut line_iter = input::lines(data);
+    let mut scanners = Vec::new();
+
+    while let Some(line) = line_iter.next() {
+        if line.text().starts_with("---") {
+            scanners.push(parse_scanner(&mut line_iter)?);
+        } else if !line.text().is_empty() {
+            return Err(line.error("Expected a scanner header.").into());
+        }
+    }
+
+    Ok(Scanners::new(scanners))
+}
+
+/// The reports from every scanner, each introduced by a header like
+/// `--- scanner 0 ---`.
+#[derive(Debug, Default, Clone)]
+pub struct Scanners<const N: usize> {
+    scanners: Vec<Scanner<N>>,
+    // Both parts need the alignment, so the first to ask computes it.
+    alignment: OnceLock<AlignResult<N>>,
+}
+
+impl<const N: usize> Scanners<N> {
+    pub fn new(scanners: Vec<Scanner<N>>) -> Self {
+        Self {
+            scanners,
+            alignment: OnceLock::new(),
+        }
+    }
+
+    pub fn scanners(&self) -> &[Scanner<N>] {
+        &self.scanners
+    }
+
+    /// The scanners aligned with each other, computed on first use, or an
+    /// error naming the scanners that overlap with none of the others. Every
+    /// call must pass the same `min_overlap`.
+    fn alignment(&self, min_overlap: usize) -> Result<&Alignment<N>> {
+        match self
+            .alignment
+            .get_or_init(|| align(&self.scanners, min_overlap))
+        {
+            Ok(alignment) => {
+                debug_assert_eq!(min_overlap, alignment.min_overlap);
+                Ok(alignment)
+            }
+            Err(unplaced) => {
+                let unplaced = unplaced.iter().map(usize::to_string).collect::<Vec<_>>();
+                Err(Error::unsolvable(format!(
+                    "Unable to place scanners {} relative to scanner 0.",
+                    unplaced.join(", ")
+                )))
+            }
+        }
+    }
+}
+
+impl<const N: usize> PartialEq for Scanners<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scanners == other.scanners
+    }
+}
+
+impl<const N: usize> Eq for Scanners<N> {}
+
+impl<const N: usize> FromStr for Scanners<N> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl<const N: usize> fmt::Display for Scanners<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, scanner) in self.scanners.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "--- scanner {} ---", i)?;
+            write!(f, "{}", scanner)?;
+        }
+        Ok(())
+    }
+}
+
+/// The beacons one scanner can see, relative to the scanner, one per line.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Scanner<const N: usize> {
+    points: Vec<Vector<N>>,
+}
+
+impl<const N: usize> Scanner<N> {
+    pub fn new(points: Vec<Vector<N>>) -> Self {
+        Self { points }
+    }
+
+    pub fn points(&self) -> &[Vector<N>] {
+        &self.points
+    }
+
+    fn match_beacons(
+        &self,
+        other: &Self,
+        rotations: &[Matrix<N>],
+        min_overlap: usize,
+    ) -> Option<(Self, Vector<N>)> {
+        instrument::count("day19 scanner pairs compared", 1);
+        for rotation in rotations.iter() {
+            instrument::count("day19 rotations tried", 1);
+            let rotated_scanner = other.rotate(rotation);
+
+            for &point in self.points.iter() {
+                for &transformed_point in rotated_scanner.points.iter() {
+                    let offset = transformed_point - point;
+                    instrument::count("day19 offsets tried", 1);
+                    let num_overlapping_beacons = self
+                        .points
+                        .iter()
+                        .filter(|&&p| rotated_scanner.points.contains(&(p + offset)))
+                        .copied()
+                        .count();
+
+                    if num_overlapping_beacons >= min_overlap {
+                        return Some((rotated_scanner.translate(&-offset), offset));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn rotate(&self, rotation: &Matrix<N>) -> Self {
+        Self::new(self.points.iter().map(|&p| *rotation * p).collect())
+    }
+
+    fn translate(&self, offset: &Vector<N>) -> Self {
+        Self::new(self.points.iter().map(|&p| p + *offset).collect())
+    }
+}
+
+impl<const N: usize> FromStr for Scanner<N> {
+    type Err = Error;
+
+    /// Parses the beacons of one scanner, with or without its header.
+    fn from_str(s: &str) -> Result<Self> {
+        let mut line_iter = input::lines(s).peekable();
+        if let Some(line) = line_iter.peek() {
+            if line.text().starts_with("---") {
+                line_iter.next();
+            }
+        }
+        parse_scanner(&mut line_iter)
+    }
+}
+
+impl<const N: usize> fmt::Display for Scanner<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for point in self.points.iter() {
+            writeln!(f, "{}", point)?;
+        }
+        Ok(())
+    }
+}
+
+/// The scanners placed relative to scanner 0: every beacon in its frame, and
+/// where each scanner sits.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Alignment<const N: usize> {
+    beacons: Vec<Vector<N>>,
+    positions: Vec<Vector<N>>,
+    min_overlap: usize,
+}
+
+/// An alignment, or the indices of the scanners that couldn't be placed.
+type AlignResult<const N: usize> = std::result::Result<Alignment<N>, Vec<usize>>;
+
+impl<const N: usize> Alignment<N> {
+    fn beacons(&self) -> &[Vector<N>] {
+        &self.beacons
+    }
+
+    /// The position of each scanner, by its index in the input.
+    fn scanner_positions(&self) -> &[Vector<N>] {
+        &self.positions
+    }
+}
+
+fn align<const N: usize>(scanners: &[Scanner<N>], min_overlap: usize) -> AlignResult<N> {
+    let rotations = geometry::rotations::<N>();
+    let mut beacons = HashSet::new();
+    let mut positions = vec![None; scanners.len()];
+    positions[0] = Some(Vector::zero());
+
+    let mut scanner_queue = VecDeque::new();
+    scanner_queue.push_back(scanners[0].clone());
+
+    let mut unplaced = (1..scanners.len()).collect::<Vec<_>>();
+
+    while let Some(scanner) = scanner_queue.pop_front() {
+        beacons.extend(scanner.points.iter().copied());
+
+        let matches = pool::map(&unplaced, |&i| {
+            scanner.match_beacons(&scanners[i], &rotations, min_overlap)
+        });
+
+        let mut still_unplaced = Vec::new();
+        for (i, matched) in unplaced.into_iter().zip(matches) {
+            match matched {
+                Some((transformed_scanner, offset)) => {
+                    scanner_queue.push_back(transformed_scanner);
+                    positions[i] = Some(-offset);
+                }
+                None => still_unplaced.push(i),
+            }
+        }
+        unplaced = still_unplaced;
+    }
+
+    if !unplaced.is_empty() {
+        return Err(unplaced);
+    }
+
+    Ok(Alignment {
+        beacons: beacons.into_iter().collect(),
+        positions: positions.into_iter().flatten().collect(),
+        min_overlap,
+    })
+}
+
+fn compute_max_manhattan_distance<const N: usize>(offsets: &[Vector<N>]) -> i32 {
+    let mut max = 0;
+    for (i, first) in offsets.iter().enumerate() {
+        for second in offsets.iter().skip(i + 1) {
+            let manhattan_distance = (*first - *second).manhattan();
+            if manhattan_distance > max {
+                max = manhattan_distance;
+            }
+        }
+    }
+    max
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day19 {
+    min_overlap: usize,
+}
+
+impl Default for Day19 {
+    fn default() -> Self {
+        Self { min_overlap: 12 }
+    }
+}
+
+impl Solution for Day19 {
+    type Input = Scanners<NDIMS>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let scanners = parse_input(input)?;
+        if scanners.scanners().is_empty() {
+            return Err(ParseError::new(1, 1, "No scanners found in input.").into());
+        }
+        Ok(scanners)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        Ok(input.alignment(self.min_overlap)?.beacons().len())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let alignment = input.alignment(self.min_overlap)?;
+        Ok(compute_max_manhattan_distance(
+            alignment.scanner_positions(),
+        ))
+    }
+
+    #[cfg(feature = "json")]
+    fn extras(&self, input: &Self::Input) -> Result<json::Value> {
+        let positions = input
+            .alignment(self.min_overlap)?
+            .scanner_positions()
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                json::Value::object()
+                    .with("scanner", i)
+                    .with("position", p.0.to_vec())
+            })
+            .collect::<Vec<_>>();
+        Ok(json::Value::object().with("scanner_positions", positions))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "min_overlap" => self.min_overlap = config::parse_value(key, value)?,
+            _ => return Err(config::unknown_key(key)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unplaced_scanner() {
+        let input = "--- scanner 0 ---\n0,0,0\n1,0,0\n0,2,0\n0,0,3\n\n\
+                     --- scanner 1 ---\n10,10,10\n11,10,10\n10,12,10\n10,10,13\n\n\
+                     --- scanner 2 ---\n0,0,0\n5,0,0\n0,7,0\n0,0,11\n";
+        let day = Day19 { min_overlap: 3 };
+        let scanners = day.parse(input).unwrap();
+        for result in [
+            day.part1(&scanners).map(drop),
+            day.part2(&scanners).map(drop),
+        ] {
+            match result {
+                Err(Error::Unsolvable(message)) => {
+                    assert_eq!(message, "Unable to place scanners 2 relative to scanner 0.")
+                }
+                other => panic!("expected an unsolvable error, got {:?}", other),
+            }
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::input::{self, InputLine};
+use crate::solution::Streaming;
+use crate::Solution;
+
+/// The direction a submarine command moves in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Forward,
+    Down,
+    Up,
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_direction(&InputLine::new(1, s), s)
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction::Forward => "forward",
+            Direction::Down => "down",
+            Direction::Up => "up",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single line of the planned course, such as `forward 5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Command {
+    pub direction: Direction,
+    pub distance: i64,
+}
+
+impl Command {
+    pub fn new(direction: Direction, distance: i64) -> Self {
+        Self {
+            direction,
+            distance,
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_line(InputLine::new(1, s))
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.direction, self.distance)
+    }
+}
+
+/// The submarine's planned course.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Course {
+    commands: Vec<Command>,
+}
+
+impl Course {
+    pub fn new(commands: Vec<Command>) -> Self {
+        Self { commands }
+    }
+
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+}
+
+impl FromStr for Course {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let commands = input::lines(s).map(parse_line).collect::<Result<_>>()?;
+        Ok(Self::new(commands))
+    }
+}
+
+impl fmt::Display for Course {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for command in self.commands.iter() {
+            writeln!(f, "{}", command)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of checked arithmetic, or an error once the course leaves the
+/// range of an `i64`.
+fn in_range(value: Option<i64>) -> Result<i64> {
+    value.ok_or_else(|| Error::unsolvable("The course goes too far to track."))
+}
+
+trait CommandProcessor {
+    fn process(&mut self, command: &Command) -> Result<()>;
+
+    /// The final horizontal position multiplied by the final depth.
+    fn answer(&self) -> Result<i64>;
+}
+
+#[derive(Debug, Default)]
+struct BasicProcessor {
+    horizontal: i64,
+    depth: i64,
+}
+
+impl CommandProcessor for BasicProcessor {
+    fn process(&mut self, command: &Command) -> Result<()> {
+        match command.direction {
+            Direction::Forward => {
+                self.horizontal = in_range(self.horizontal.checked_add(command.distance))?
+            }
+            Direction::Down => self.depth = in_range(self.depth.checked_add(command.distance))?,
+            Direction::Up => self.depth = in_range(self.depth.checked_sub(command.distance))?,
+        }
+        Ok(())
+    }
+
+    fn answer(&self) -> Result<i64> {
+        in_range(self.horizontal.checked_mul(self.depth))
+    }
+}
+
+#[derive(Debug, Default)]
+struct AimProcessor {
+    aim: i64,
+    depth: i64,
+    horizontal: i64,
+}
+
+impl CommandProcessor for AimProcessor {
+    fn process(&mut self, command: &Command) -> Result<()> {
+        match command.direction {
+            Direction::Forward => {
+                self.horizontal = in_range(self.horizontal.checked_add(command.distance))?;
+                let descent = in_range(self.aim.checked_mul(command.distance))?;
+                self.depth = in_range(self.depth.checked_add(descent))?;
+            }
+            Direction::Down => self.aim = in_range(self.aim.checked_add(command.distance))?,
+            Direction::Up => self.aim = in_range(self.aim.checked_sub(command.distance))?,
+        }
+        Ok(())
+    }
+
+    fn answer(&self) -> Result<i64> {
+        in_range(self.horizontal.checked_mul(self.depth))
+    }
+}
+
+fn parse_direction(line: &InputLine, text: &str) -> Result<Direction> {
+    match text {
+        "forward" => Ok(Direction::Forward),
+        "down" => Ok(Direction::Down),
+        "up" => Ok(Direction::Up),
+        _ => Err(line
+            .error_at(text, format!("Unknown direction '{}'.", text))
+            .into()),
+    }
+}
+
+fn parse_line(line: InputLine) -> Result<Command> {
+    let mut iter = line.text().split_ascii_whitespace();
+    let direction = iter
+        .next()
+        .ok_or_else(|| line.error_at_end("Expected a direction."))?;
+    let direction = parse_direction(&line, direction)?;
+    let distance = iter
+        .next()
+        .ok_or_else(|| line.error_at_end("Expected a distance."))?;
+    let distance = distance.parse::<i64>().map_err(|e| {
+        line.error_at(distance, "Unable to parse distance.")
+            .with_source(e)
+    })?;
+
+    Ok(Command::new(direction, distance))
+}
+
+fn calculate_depth_and_distance<T: CommandProcessor + Default>(commands: &[Command]) -> Result<T> {
+    let mut processor = T::default();
+
+    for command in commands {
+        processor.process(command)?;
+    }
+
+    Ok(processor)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Course;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        calculate_depth_and_distance::<BasicProcessor>(input.commands())?.answer()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        calculate_depth_and_distance::<AimProcessor>(input.commands())?.answer()
+    }
+}
+
+impl Streaming for Day2 {
+    fn solve_stream(&self, reader: &mut dyn BufRead) -> Result<(i64, i64)> {
+        let mut lines = input::Reader::lines(reader);
+        let mut basic = BasicProcessor::default();
+        let mut aim = AimProcessor::default();
+        while let Some(line) = lines.next_record()? {
+            let command = parse_line(line)?;
+            basic.process(&command)?;
+            aim.process(&command)?;
+        }
+        Ok((basic.answer()?, aim.answer()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_course() {
+        let course = "forward 3000000000\ndown 3000000000\n"
+            .parse::<Course>()
+            .unwrap();
+        assert_eq!(9_000_000_000_000_000_000, Day2.part1(&course).unwrap());
+        // With an aim of zero the submarine never dives.
+        assert_eq!(0, Day2.part2(&course).unwrap());
+
+        let course = "forward 4000000000\ndown 4000000000\n"
+            .parse::<Course>()
+            .unwrap();
+        assert!(matches!(Day2.part1(&course), Err(Error::Unsolvable(_))));
+    }
+}
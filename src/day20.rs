@@ -0,0 +1,331 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config;
+use crate::error::{Error, ParseError, Result};
+use crate::grid::Grid;
+use crate::input::{self, InputLine};
+use crate::memo::{FnvBuildHasher, Memo};
+use crate::snapshot::{self, Snapshot};
+use crate::viz::{self, Frame};
+use crate::Solution;
+
+fn parse_pixels(line: InputLine) -> Result<Vec<bool>> {
+    line.text()
+        .char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => {
+                let message = format!("Unexpected pixel '{}'.", c);
+                Err(line.error_at(&line.text()[i..], message).into())
+            }
+        })
+        .collect()
+}
+
+fn parse_image<'a>(lines: impl IntoIterator<Item = InputLine<'a>>) -> Result<Image> {
+    let pixels = Grid::from_lines(lines, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    Ok(Image::new(pixels))
+}
+
+fn parse_input(data: &str) -> Result<TrenchMap> {
+    let mut line_iter = input::lines(data);
+    let line = line_iter
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "Input file is empty."))?;
+    let algorithm = parse_pixels(line)?;
+    if algorithm.len() != 512 {
+        return Err(line
+            .error("Expected an enhancement algorithm of 512 pixels.")
+            .into());
+    }
+
+    line_iter.next();
+
+    let image = parse_image(line_iter)?;
+
+    Ok(TrenchMap { algorithm, image })
+}
+
+fn render_pixels(pixels: &[bool]) -> String {
+    pixels.iter().map(|&p| if p { '#' } else { '.' }).collect()
+}
+
+/// The image enhancement algorithm, 512 pixels on the first line, followed by
+/// a blank line and the input image.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TrenchMap {
+    algorithm: Algorithm,
+    image: Image,
+}
+
+impl TrenchMap {
+    pub fn new(algorithm: Algorithm, image: Image) -> Result<Self> {
+        if algorithm.len() != 512 {
+            return Err(Error::invalid_state(
+                "Expected an enhancement algorithm of 512 pixels.",
+            ));
+        }
+        Ok(Self { algorithm, image })
+    }
+
+    pub fn algorithm(&self) -> &[bool] {
+        &self.algorithm
+    }
+
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Applies the algorithm `num_iterations` times, growing the image by one
+    /// pixel on every side each time.
+    pub fn enhance(&self, num_iterations: usize) -> Image {
+        let mut processor = ImageProcessor::new(self.algorithm.clone());
+        for iteration in 0..num_iterations {
+            viz::frame(|| {
+                let image = self
+                    .image
+                    .process(&mut processor, num_iterations, iteration);
+                image.frame()
+            });
+        }
+        let image = self
+            .image
+            .process(&mut processor, num_iterations, num_iterations);
+        viz::frame(|| image.frame());
+        image
+    }
+}
+
+impl FromStr for TrenchMap {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl fmt::Display for TrenchMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", render_pixels(&self.algorithm))?;
+        writeln!(f)?;
+        write!(f, "{}", self.image)
+    }
+}
+
+/// An image of light (`#`) and dark (`.`) pixels. Pixels beyond its edges
+/// all match the background, which is dark unless enhancement lit it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<bool>,
+    background: bool,
+}
+
+impl Image {
+    pub fn new(pixels: Grid<bool>) -> Self {
+        Self {
+            pixels,
+            background: false,
+        }
+    }
+
+    pub fn with_background(self, background: bool) -> Self {
+        Self { background, ..self }
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    pub fn background(&self) -> bool {
+        self.background
+    }
+
+    /// Applies the algorithm `num_iterations` times over a canvas grown by
+    /// `border` pixels on every side.
+    fn process(
+        &self,
+        processor: &mut ImageProcessor,
+        border: usize,
+        num_iterations: usize,
+    ) -> Image {
+        let output_width = self.pixels.width() + 2 * border;
+        let output_height = self.pixels.height() + 2 * border;
+        let border = border as isize;
+
+        let mut background = self.background;
+        for _ in 0..num_iterations {
+            background = processor.algorithm[if background { 511 } else { 0 }];
+        }
+
+        let pixels = Grid::from_fn(output_width, output_height, |i, j| {
+            let (row, col) = (i as isize - border, j as isize - border);
+            processor.process(&|r, c| self.pixel_value(r, c), row, col, num_iterations)
+        });
+        Image::new(pixels).with_background(background)
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.pixels, |&p| if p { 255 } else { 0 })
+    }
+
+    pub fn num_lit_pixels(&self) -> usize {
+        self.pixels.iter().filter(|&&p| p).count()
+    }
+
+    fn pixel_value(&self, row: isize, col: isize) -> bool {
+        self.pixels
+            .get(row, col)
+            .copied()
+            .unwrap_or(self.background)
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered = self.pixels.render(|&p| if p { '#' } else { '.' });
+        write!(f, "{}", rendered)
+    }
+}
+
+impl FromStr for Image {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_image(input::lines(s))
+    }
+}
+
+pub type Algorithm = Vec<bool>;
+
+struct ImageProcessor {
+    algorithm: Algorithm,
+    cache: Memo<(isize, isize, usize), bool, FnvBuildHasher>,
+}
+
+impl ImageProcessor {
+    fn new(algorithm: Algorithm) -> Self {
+        Self {
+            algorithm,
+            cache: Memo::with_hasher("day20 pixels", FnvBuildHasher::default()),
+        }
+    }
+
+    fn process(
+        &mut self,
+        get_pixel_value: &impl Fn(isize, isize) -> bool,
+        row: isize,
+        col: isize,
+        num_iterations: usize,
+    ) -> bool {
+        if let Some(result) = self.cache.get(&(row, col, num_iterations)) {
+            return *result;
+        }
+
+        let result = if num_iterations == 0 {
+            get_pixel_value(row, col)
+        } else {
+            let mut pixels = [false; 9];
+            for (i, r) in (-1..2).enumerate() {
+                for (j, c) in (-1..2).enumerate() {
+                    pixels[i * 3 + j] =
+                        self.process(get_pixel_value, row + r, col + c, num_iterations - 1);
+                }
+            }
+
+            let mut index = 0;
+            for (i, &v) in pixels.iter().rev().enumerate() {
+                if v {
+                    index |= 1 << i;
+                }
+            }
+            self.algorithm[index]
+        };
+
+        self.cache.insert((row, col, num_iterations), result);
+        result
+    }
+}
+
+/// Enhances the image for `part`, resuming from and checkpointing to
+/// snapshots when asked. Checkpointing enhances in chunks between them.
+fn enhance(input: &TrenchMap, part: usize, num_iterations: usize) -> Result<Image> {
+    let (mut map, mut step) = match snapshot::resume(20, part) {
+        Some(snapshot) => {
+            let map = snapshot.state::<TrenchMap>()?;
+            let background = match snapshot.value::<char>("background")? {
+                '#' => true,
+                '.' => false,
+                c => {
+                    return Err(Error::invalid_state(format!(
+                        "Invalid snapshot background '{}'.",
+                        c
+                    )))
+                }
+            };
+            let image = map.image.with_background(background);
+            (TrenchMap::new(map.algorithm, image)?, snapshot.step())
+        }
+        None => (input.clone(), 0),
+    };
+
+    let interval = snapshot::interval().unwrap_or(usize::MAX);
+    while step < num_iterations {
+        let chunk = (num_iterations - step).min(interval - step % interval);
+        map.image = map.enhance(chunk);
+        step += chunk;
+        snapshot::checkpoint(step, || {
+            let background = if map.image.background { '#' } else { '.' };
+            Snapshot::new(20, part, step, &map).with("background", background)
+        });
+    }
+
+    Ok(map.image)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day20 {
+    part1_iterations: usize,
+    part2_iterations: usize,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Self {
+            part1_iterations: 2,
+            part2_iterations: 50,
+        }
+    }
+}
+
+impl Solution for Day20 {
+    type Input = TrenchMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        Ok(enhance(input, 1, self.part1_iterations)?.num_lit_pixels())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        Ok(enhance(input, 2, self.part2_iterations)?.num_lit_pixels())
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "part1_iterations" => self.part1_iterations = config::parse_value(key, value)?,
+            "part2_iterations" => self.part2_iterations = config::parse_value(key, value)?,
+            _ => return Err(config::unknown_key(key)),
+        }
+        Ok(())
+    }
+}
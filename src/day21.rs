@@ -0,0 +1,427 @@
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+use crate::config;
+use crate::error::{Error, Result};
+use crate::input::{self, InputLine};
+use crate::memo::Memo;
+use crate::simulation::Simulation;
+use crate::Solution;
+
+fn parse_line(line: InputLine, board_size: usize) -> Result<Player> {
+    let position = line
+        .text()
+        .split(": ")
+        .nth(1)
+        .ok_or_else(|| line.error_at_end("Expected ': ' followed by a starting position."))?;
+    let position = position.parse::<usize>().map_err(|e| {
+        line.error_at(position, "Unable to parse integer.")
+            .with_source(e)
+    })?;
+    if !(1..=board_size).contains(&position) {
+        return Err(line
+            .error_at_end(format!(
+                "Starting position must be between 1 and {}.",
+                board_size
+            ))
+            .into());
+    }
+
+    Ok(Player::new(position))
+}
+
+const BOARD_SIZE: usize = 10;
+
+fn parse_input(data: &str, board_size: usize) -> Result<StartingPositions> {
+    let players = input::lines(data)
+        .map(|line| parse_line(line, board_size))
+        .collect::<Result<_>>()?;
+    Ok(StartingPositions::new(players))
+}
+
+/// The players' starting positions, one per line, such as
+/// `Player 1 starting position: 4`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StartingPositions {
+    players: Vec<Player>,
+}
+
+impl StartingPositions {
+    pub fn new(players: Vec<Player>) -> Self {
+        Self { players }
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+}
+
+impl FromStr for StartingPositions {
+    type Err = Error;
+
+    /// Parses starting positions on the standard board of 10 spaces.
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s, BOARD_SIZE)
+    }
+}
+
+impl fmt::Display for StartingPositions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, player) in self.players.iter().enumerate() {
+            writeln!(f, "Player {} starting position: {}", i + 1, player.position)?;
+        }
+        Ok(())
+    }
+}
+
+/// A player's position on the board, counted from 1, and their score.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Player {
+    position: usize,
+    score: usize,
+}
+
+impl Player {
+    pub fn new(position: usize) -> Self {
+        Self { position, score: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn score(&self) -> usize {
+        self.score
+    }
+
+    fn add_to_position(&mut self, increase: usize, board_size: usize) {
+        self.position += increase;
+        while self.position > board_size {
+            self.position -= board_size;
+        }
+        self.score += self.position;
+    }
+}
+
+trait Dice {
+    fn roll(&mut self) -> Vec<usize>;
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PracticeDice {
+    current: usize,
+}
+
+impl PracticeDice {
+    fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Dice for PracticeDice {
+    fn roll(&mut self) -> Vec<usize> {
+        let mut sum = 0;
+        for _ in 0..3 {
+            sum += self.current;
+            self.current += 1;
+            if self.current > 100 {
+                self.current = 1;
+            }
+        }
+        vec![sum]
+    }
+}
+
+impl Default for PracticeDice {
+    fn default() -> Self {
+        Self { current: 1 }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct DiracDice {
+    rolls: Vec<usize>,
+}
+
+impl DiracDice {
+    fn new() -> Self {
+        let mut rolls = Vec::with_capacity(27);
+        for i in 1..4 {
+            for j in 1..4 {
+                for k in 1..4 {
+                    rolls.push(i + j + k);
+                }
+            }
+        }
+        Self { rolls }
+    }
+}
+
+impl Dice for DiracDice {
+    fn roll(&mut self) -> Vec<usize> {
+        self.rolls.clone()
+    }
+}
+
+trait Game {
+    fn play(&mut self, players: &mut [Player]);
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct PracticeGame {
+    dice: PracticeDice,
+    roll_count: usize,
+    board_size: usize,
+    winning_score: usize,
+}
+
+impl PracticeGame {
+    fn new(dice: PracticeDice, board_size: usize, winning_score: usize) -> Self {
+        Self {
+            dice,
+            roll_count: 0,
+            board_size,
+            winning_score,
+        }
+    }
+
+    /// Rolls for `player` and moves them, returning whether they've won.
+    fn take_turn(&mut self, player: &mut Player) -> bool {
+        let dice_rolls = self.dice.roll();
+        self.roll_count += 3;
+        let roll: usize = dice_rolls[0];
+        player.add_to_position(roll, self.board_size);
+
+        player.score >= self.winning_score
+    }
+}
+
+impl Game for PracticeGame {
+    fn play(&mut self, players: &mut [Player]) {
+        'game_loop: loop {
+            for player in players.iter_mut() {
+                if self.take_turn(player) {
+                    break 'game_loop;
+                }
+            }
+        }
+    }
+}
+
+/// Plays the practice game one turn per step.
+#[derive(Debug, Clone)]
+struct PracticeSimulation {
+    game: PracticeGame,
+    players: Vec<Player>,
+    turn: usize,
+    finished: bool,
+}
+
+impl Simulation for PracticeSimulation {
+    fn step(&mut self) -> Option<String> {
+        let turn = self.turn;
+        self.turn = (turn + 1) % self.players.len();
+        if !self.game.take_turn(&mut self.players[turn]) {
+            return None;
+        }
+
+        self.finished = true;
+        let min_score = self.players.iter().map(|p| p.score).min().unwrap();
+        Some(format!(
+            "Player {} wins with {} points; the losing score times the rolls is {}.",
+            turn + 1,
+            self.players[turn].score,
+            min_score * self.game.roll_count
+        ))
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished || self.players.is_empty()
+    }
+}
+
+impl fmt::Display for PracticeSimulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Rolls: {}", self.game.roll_count)?;
+        for (i, player) in self.players.iter().enumerate() {
+            writeln!(
+                f,
+                "Player {}: position {}, score {}",
+                i + 1,
+                player.position,
+                player.score
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn practice(players: &mut [Player], board_size: usize, winning_score: usize) -> Result<usize> {
+    if players.is_empty() {
+        return Err(Error::unsolvable("Unable to play without players."));
+    }
+
+    let mut game = PracticeGame::new(PracticeDice::new(), board_size, winning_score);
+    game.play(players);
+
+    let min_score = players.iter().map(|p| p.score).min().unwrap();
+
+    Ok(min_score * game.roll_count)
+}
+
+#[derive(Debug, Clone)]
+struct DiracGame {
+    rolls: Vec<usize>,
+    /// `None` if there are too many winning universes to count.
+    win_counts: Option<Vec<usize>>,
+    result_cache: Memo<(Vec<Player>, usize), Vec<usize>>,
+    board_size: usize,
+    winning_score: usize,
+}
+
+impl DiracGame {
+    fn new(
+        mut dice: DiracDice,
+        num_players: usize,
+        board_size: usize,
+        winning_score: usize,
+    ) -> Self {
+        Self {
+            rolls: dice.roll(),
+            win_counts: Some(vec![0; num_players]),
+            result_cache: Memo::new("day21 win counts"),
+            board_size,
+            winning_score,
+        }
+    }
+
+    fn play_recursive(
+        &mut self,
+        players: &[Player],
+        turn: usize,
+        totals: &mut [usize],
+    ) -> Option<()> {
+        let turn = if turn >= players.len() { 0 } else { turn };
+        let cache_key = (players.to_owned(), turn);
+        if let Some(result) = self.result_cache.get(&cache_key) {
+            return add_counts(totals, result);
+        }
+
+        let mut win_counts = vec![0; players.len()];
+
+        for i in 0..self.rolls.len() {
+            let mut new_players = players.to_owned();
+
+            let current_player = &mut new_players[turn];
+            current_player.add_to_position(self.rolls[i], self.board_size);
+
+            if current_player.score >= self.winning_score {
+                win_counts[turn] += 1;
+            } else {
+                self.play_recursive(&new_players, turn + 1, &mut win_counts)?;
+            }
+        }
+
+        add_counts(totals, &win_counts)?;
+        self.result_cache.insert(cache_key, win_counts);
+        Some(())
+    }
+}
+
+fn add_counts(totals: &mut [usize], counts: &[usize]) -> Option<()> {
+    for (total, count) in totals.iter_mut().zip(counts) {
+        *total = total.checked_add(*count)?;
+    }
+    Some(())
+}
+
+impl Game for DiracGame {
+    fn play(&mut self, players: &mut [Player]) {
+        let mut win_counts = vec![0; players.len()];
+        self.win_counts = self
+            .play_recursive(players, 0, &mut win_counts)
+            .map(|_| win_counts);
+    }
+}
+
+fn play(players: &mut [Player], board_size: usize, winning_score: usize) -> Result<usize> {
+    if players.is_empty() {
+        return Err(Error::unsolvable("Unable to play without players."));
+    }
+
+    let mut game = DiracGame::new(DiracDice::new(), players.len(), board_size, winning_score);
+    game.play(players);
+
+    let win_counts = game
+        .win_counts
+        .ok_or_else(|| Error::unsolvable("Too many winning universes to count."))?;
+    Ok(win_counts.iter().max().copied().unwrap())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day21 {
+    board_size: usize,
+    practice_score: usize,
+    winning_score: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Self {
+            board_size: BOARD_SIZE,
+            practice_score: 1000,
+            winning_score: 21,
+        }
+    }
+}
+
+impl Solution for Day21 {
+    type Input = StartingPositions;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_input(input, self.board_size)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        practice(
+            &mut input.players().to_vec(),
+            self.board_size,
+            self.practice_score,
+        )
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        play(
+            &mut input.players().to_vec(),
+            self.board_size,
+            self.winning_score,
+        )
+    }
+
+    fn simulation(&self, input: &Self::Input) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(PracticeSimulation {
+            game: PracticeGame::new(PracticeDice::new(), self.board_size, self.practice_score),
+            players: input.players().to_vec(),
+            turn: 0,
+            finished: false,
+        }))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "board_size" => {
+                self.board_size = config::parse_value(key, value)?;
+                if self.board_size == 0 {
+                    return Err(Error::invalid_config("'board_size' must be at least 1."));
+                }
+            }
+            "practice_score" => self.practice_score = config::parse_value(key, value)?,
+            "winning_score" => self.winning_score = config::parse_value(key, value)?,
+            _ => return Err(config::unknown_key(key)),
+        }
+        Ok(())
+    }
+}
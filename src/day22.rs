@@ -0,0 +1,416 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config;
+use crate::error::{Error, Result};
+use crate::geometry::{Aabb, Vector};
+use crate::input::{self, InputLine};
+use crate::instrument;
+use crate::Solution;
+
+const NDIMS: usize = 3;
+
+fn parse_cuboid(line: InputLine, spec: &str) -> Result<Cuboid> {
+    let mut values = [0; 6];
+    let mut count = 0;
+
+    for (i, axis_spec) in spec.split(',').enumerate() {
+        if i >= NDIMS {
+            return Err(line
+                .error_at(axis_spec, "Unexpected extra axis.")
+                .with_expected("only 'x', 'y' and 'z' ranges")
+                .into());
+        }
+        let axis_spec = axis_spec.split('=').nth(1).ok_or_else(|| {
+            line.error_at(axis_spec, "Expected an axis range.")
+                .with_expected("a range like 'x=-20..26'")
+        })?;
+        for (j, s) in axis_spec.split("..").enumerate() {
+            if j >= 2 {
+                return Err(line
+                    .error_at(s, "Unexpected extra range bound.")
+                    .with_expected("a range like 'x=-20..26'")
+                    .into());
+            }
+            values[2 * i + j] = s.parse::<i32>().map_err(|e| {
+                line.error_at(s, "Unable to parse integer.")
+                    .with_expected("an integer bound like '-20'")
+                    .with_source(e)
+            })?;
+            count += 1;
+        }
+    }
+
+    if count != 2 * NDIMS {
+        return Err(line
+            .error_at_end("Expected lower and upper bounds for three axes.")
+            .with_expected("'x=<min>..<max>,y=<min>..<max>,z=<min>..<max>'")
+            .into());
+    }
+
+    Ok(Cuboid::new(
+        Vector::new([values[0], values[2], values[4]]),
+        Vector::new([values[1] + 1, values[3] + 1, values[5] + 1]),
+    ))
+}
+
+fn parse_line(line: InputLine) -> Result<Instruction> {
+    let mut split_iter = line.text().split(' ');
+    let turn_on = match split_iter.next() {
+        Some("on") => true,
+        Some("off") => false,
+        Some(s) => {
+            return Err(line
+                .error_at(s, "Unknown instruction.")
+                .with_expected("'on' or 'off'")
+                .into())
+        }
+        None => return Err(line.error("Unknown instruction.").into()),
+    };
+    let spec = split_iter
+        .next()
+        .ok_or_else(|| line.error_at_end("Expected a cuboid specification."))?;
+
+    let cuboid = parse_cuboid(line, spec)?;
+
+    Ok(Instruction::new(turn_on, cuboid))
+}
+
+fn parse_input(data: &str) -> Result<RebootSteps> {
+    let instructions = input::lines(data).map(parse_line).collect::<Result<_>>()?;
+    Ok(RebootSteps::new(instructions))
+}
+
+/// A cuboid of cubes, written with inclusive ranges as
+/// `x=-20..26,y=-36..17,z=-47..7`. The upper corner is stored exclusively.
+pub type Cuboid = Aabb<NDIMS>;
+
+impl FromStr for Cuboid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_cuboid(InputLine::new(1, s), s)
+    }
+}
+
+impl fmt::Display for Cuboid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, axis) in ["x", "y", "z"].iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}={}..{}", axis, self.lower[i], self.upper[i] - 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// One reboot step, such as `on x=10..12,y=10..12,z=10..12`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    turn_on: bool,
+    cuboid: Cuboid,
+}
+
+impl Instruction {
+    pub fn new(turn_on: bool, cuboid: Cuboid) -> Self {
+        Self { turn_on, cuboid }
+    }
+
+    pub fn turn_on(&self) -> bool {
+        self.turn_on
+    }
+
+    pub fn cuboid(&self) -> &Cuboid {
+        &self.cuboid
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_line(InputLine::new(1, s))
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = if self.turn_on { "on" } else { "off" };
+        write!(f, "{} {}", state, self.cuboid)
+    }
+}
+
+/// The reactor's reboot steps, in order.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RebootSteps {
+    instructions: Vec<Instruction>,
+}
+
+impl RebootSteps {
+    pub fn new(instructions: Vec<Instruction>) -> Self {
+        Self { instructions }
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+}
+
+impl FromStr for RebootSteps {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl fmt::Display for RebootSteps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for instruction in self.instructions.iter() {
+            writeln!(f, "{}", instruction)?;
+        }
+        Ok(())
+    }
+}
+
+fn in_bounds<const N: usize>(point: &Vector<N>, region: i32) -> bool {
+    point.iter().any(|p| p.abs() <= region + 1)
+}
+
+fn add_cuboid<const N: usize>(active: Vec<Aabb<N>>, to_add: &Aabb<N>) -> Vec<Aabb<N>> {
+    let mut new_active = remove_cuboid(active, to_add);
+    new_active.push(*to_add);
+    new_active
+}
+
+fn remove_cuboid<const N: usize>(active: Vec<Aabb<N>>, to_remove: &Aabb<N>) -> Vec<Aabb<N>> {
+    let mut new_active = Vec::new();
+
+    for cuboid in active {
+        if !cuboid.intersects(to_remove) {
+            new_active.push(cuboid);
+            continue;
+        }
+
+        if to_remove.contains(&cuboid) {
+            continue;
+        }
+
+        let mut new_cuboids = cuboid.difference(to_remove);
+        new_active.append(&mut new_cuboids);
+    }
+
+    new_active
+}
+
+fn count_cubes<const N: usize>(steps: impl IntoIterator<Item = (bool, Aabb<N>)>) -> u64 {
+    let mut cuboid_store = Vec::new();
+
+    for (turn_on, cuboid) in steps {
+        if turn_on {
+            cuboid_store = add_cuboid(cuboid_store, &cuboid);
+        } else {
+            cuboid_store = remove_cuboid(cuboid_store, &cuboid);
+        }
+        instrument::observe("day22 stored cuboids", cuboid_store.len() as u64);
+    }
+
+    cuboid_store.iter().map(|c| c.volume()).sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day22 {
+    init_region: i32,
+}
+
+impl Default for Day22 {
+    fn default() -> Self {
+        Self { init_region: 50 }
+    }
+}
+
+impl Solution for Day22 {
+    type Input = RebootSteps;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let restricted_steps = input
+            .instructions()
+            .iter()
+            .filter(|i| {
+                in_bounds(&i.cuboid.lower, self.init_region)
+                    && in_bounds(&i.cuboid.upper, self.init_region)
+            })
+            .map(|i| (i.turn_on, i.cuboid));
+
+        Ok(count_cubes(restricted_steps) as usize)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        Ok(count_cubes(input.instructions().iter().map(|i| (i.turn_on, i.cuboid))) as usize)
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "init_region" => self.init_region = config::parse_value(key, value)?,
+            _ => return Err(config::unknown_key(key)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::generate::Rng;
+
+    fn cuboid(lower: [i32; 3], upper: [i32; 3]) -> Cuboid {
+        Cuboid::new(Vector::new(lower), Vector::new(upper))
+    }
+
+    fn random_cuboid(rng: &mut Rng) -> Cuboid {
+        let lower = Vector([(); NDIMS].map(|_| rng.range(-8, 8) as i32));
+        let size = Vector([(); NDIMS].map(|_| rng.range(1, 8) as i32));
+        Cuboid::new(lower, lower + size)
+    }
+
+    fn voxels(cuboid: &Cuboid) -> Vec<Vector<NDIMS>> {
+        let mut points = vec![cuboid.lower];
+        for axis in 0..NDIMS {
+            points = points
+                .into_iter()
+                .flat_map(|p| {
+                    (cuboid.lower[axis]..cuboid.upper[axis]).map(move |c| {
+                        let mut point = p;
+                        point[axis] = c;
+                        point
+                    })
+                })
+                .collect();
+        }
+        points
+    }
+
+    /// Switches every cube individually.
+    fn count_voxels(steps: &[(bool, Cuboid)]) -> u64 {
+        let mut lit = HashSet::new();
+        for (turn_on, cuboid) in steps {
+            for point in voxels(cuboid) {
+                if *turn_on {
+                    lit.insert(point);
+                } else {
+                    lit.remove(&point);
+                }
+            }
+        }
+        lit.len() as u64
+    }
+
+    #[test]
+    fn test_difference_matches_voxels() {
+        for seed in 0..200 {
+            let mut rng = Rng::new(seed);
+            let first = random_cuboid(&mut rng);
+            let second = random_cuboid(&mut rng);
+
+            let mut covered = HashSet::new();
+            for piece in first.difference(&second) {
+                for point in voxels(&piece) {
+                    assert!(covered.insert(point), "seed {}: overlapping pieces", seed);
+                }
+            }
+            let expected = voxels(&first)
+                .into_iter()
+                .filter(|&p| !second.contains_point(p))
+                .collect::<HashSet<_>>();
+            assert_eq!(expected, covered, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_count_cubes_matches_voxels() {
+        for seed in 0..200 {
+            let mut rng = Rng::new(seed);
+            let steps = (0..rng.range(1, 12))
+                .map(|_| (rng.chance(0.6), random_cuboid(&mut rng)))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                count_voxels(&steps),
+                count_cubes(steps.iter().copied()),
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn test_difference() {
+        let first = cuboid([0, 0, 0], [10, 10, 10]);
+        let second = cuboid([5, 5, 5], [15, 15, 15]);
+
+        let expected = vec![
+            cuboid([5, 5, 10], [10, 10, 15]),
+            cuboid([5, 10, 5], [10, 15, 10]),
+            cuboid([5, 10, 10], [10, 15, 15]),
+            cuboid([10, 5, 5], [15, 10, 10]),
+            cuboid([10, 5, 10], [15, 10, 15]),
+            cuboid([10, 10, 5], [15, 15, 10]),
+            cuboid([10, 10, 10], [15, 15, 15]),
+        ];
+
+        let mut difference = second.difference(&first);
+        difference.sort();
+
+        assert_eq!(expected, difference);
+
+        let first = cuboid([0, 0, 0], [15, 10, 10]);
+        let second = cuboid([5, 5, 5], [10, 15, 15]);
+
+        let expected = vec![
+            cuboid([5, 5, 10], [10, 10, 15]),
+            cuboid([5, 10, 5], [10, 15, 10]),
+            cuboid([5, 10, 10], [10, 15, 15]),
+        ];
+
+        let mut difference = second.difference(&first);
+        difference.sort();
+
+        assert_eq!(expected, difference);
+
+        let first = cuboid([0, 0, 5], [10, 10, 10]);
+        let second = cuboid([5, 5, 0], [15, 15, 15]);
+
+        let expected = vec![
+            cuboid([5, 5, 0], [10, 10, 5]),
+            cuboid([5, 5, 10], [10, 10, 15]),
+            cuboid([5, 10, 0], [10, 15, 5]),
+            cuboid([5, 10, 5], [10, 15, 10]),
+            cuboid([5, 10, 10], [10, 15, 15]),
+            cuboid([10, 5, 0], [15, 10, 5]),
+            cuboid([10, 5, 5], [15, 10, 10]),
+            cuboid([10, 5, 10], [15, 10, 15]),
+            cuboid([10, 10, 0], [15, 15, 5]),
+            cuboid([10, 10, 5], [15, 15, 10]),
+            cuboid([10, 10, 10], [15, 15, 15]),
+        ];
+
+        let mut difference = second.difference(&first);
+        difference.sort();
+
+        assert_eq!(expected, difference);
+    }
+
+    #[test]
+    fn test_volume() {
+        let cube = cuboid([-1, 2, -3], [5, 6, 4]);
+        assert_eq!(168, cube.volume());
+    }
+}
@@ -0,0 +1,302 @@
+use std::fmt;
+use std::iter::successors;
+use std::str::FromStr;
+
+use crate::error::{Error, ParseError, Result};
+use crate::grid::Grid;
+use crate::input;
+use crate::instrument;
+use crate::search;
+use crate::Solution;
+
+const EXTRA_LINES: [&str; 2] = ["  #D#C#B#A#", "  #D#B#A#C#"];
+
+fn parse_input(input: &str) -> Result<Configuration> {
+    let width = input
+        .lines()
+        .map(|l| l.len())
+        .max()
+        .ok_or_else(|| ParseError::new(1, 1, "Input file is empty."))?;
+    let height = input.lines().count();
+    let mut cells = Vec::new();
+
+    for line in input::lines(input) {
+        let mut line_cells = Vec::new();
+        for (j, c) in line.text().char_indices() {
+            if !matches!(c, '#' | '.' | ' ' | 'A'..='D') {
+                let message = format!("Unexpected character '{}' in burrow.", c);
+                return Err(line.error_at(&line.text()[j..], message).into());
+            }
+            line_cells.push(c);
+        }
+        while line_cells.len() < width {
+            line_cells.push(' ');
+        }
+        cells.append(&mut line_cells);
+    }
+
+    Ok(Configuration::new(Grid::from_vec(cells, width, height)))
+}
+
+/// A diagram of the burrow, showing the hallway, the side rooms and the
+/// amphipods `A` to `D` in them.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Configuration {
+    cells: Grid<char>,
+}
+
+impl Configuration {
+    pub fn new(cells: Grid<char>) -> Self {
+        Self { cells }
+    }
+
+    pub fn cells(&self) -> &Grid<char> {
+        &self.cells
+    }
+
+    /// The full diagram, with the two lines folded out of the notes
+    /// inserted below the top row of the side rooms.
+    pub fn unfolded(&self) -> Self {
+        let width = self.cells.width();
+        let mut cells = Vec::new();
+
+        for (i, row) in self.cells.rows().enumerate() {
+            cells.extend_from_slice(row);
+            if i == 2 {
+                for extra_line in EXTRA_LINES.iter() {
+                    let mut line_cells = extra_line.chars().collect::<Vec<_>>();
+                    line_cells.resize(width, ' ');
+                    cells.append(&mut line_cells);
+                }
+            }
+        }
+
+        let height = cells.len() / width.max(1);
+        Self::new(Grid::from_vec(cells, width, height))
+    }
+
+    /// The least energy needed to move every amphipod into its own room.
+    pub fn minimum_energy(&self) -> Result<usize> {
+        find_minimum_energy(self.clone())
+    }
+
+    fn get_adjacent_configurations(&self) -> Vec<(Self, usize)> {
+        let mut adjacent_configs = Vec::new();
+
+        for (i, j) in self.cells.positions() {
+            let mut configs = match self.cells[(i, j)] {
+                'A' | 'B' | 'C' | 'D' => self.get_configs_for_cell(i, j),
+                _ => {
+                    continue;
+                }
+            };
+            adjacent_configs.append(&mut configs);
+        }
+
+        adjacent_configs
+    }
+
+    fn get_configs_for_cell(&self, row: usize, col: usize) -> Vec<(Self, usize)> {
+        let position = (row, col);
+        let amphipod = self.cells[position];
+        let base_energy = self.amphipod_energy(amphipod).unwrap();
+
+        let row = row as isize;
+        let col = col as isize;
+        let candidate_positions = if row > 1 {
+            self.get_room_exit_configs(row, col, base_energy)
+        } else {
+            self.get_room_entry_config(row, col, base_energy, amphipod)
+        };
+
+        candidate_positions
+            .iter()
+            .map(|&(r, c, e)| {
+                let mut new_cells = self.cells.clone();
+                new_cells[(r as usize, c as usize)] = amphipod;
+                new_cells[position] = '.';
+                (Configuration::new(new_cells), e)
+            })
+            .collect::<Vec<_>>()
+    }
+
+    fn get_room_exit_configs(
+        &self,
+        row: isize,
+        col: isize,
+        base_energy: usize,
+    ) -> Vec<(isize, isize, usize)> {
+        let mut positions = Vec::new();
+
+        for new_row in (1..row).rev() {
+            if self.cell(new_row, col) != Some('.') {
+                return Vec::new();
+            }
+        }
+        for new_col in (col + 1)..(self.cells.width() as isize - 1) {
+            if self.cell(1, new_col) != Some('.') {
+                break;
+            } else if self.cell(2, new_col) == Some('#') {
+                let energy = calculate_energy((row, col), (1, new_col), base_energy);
+                positions.push((1, new_col, energy));
+            }
+        }
+        for new_col in (1..=col - 1).rev() {
+            if self.cell(1, new_col) != Some('.') {
+                break;
+            } else if self.cell(2, new_col) == Some('#') {
+                let energy = calculate_energy((row, col), (1, new_col), base_energy);
+                positions.push((1, new_col, energy));
+            }
+        }
+
+        positions
+    }
+
+    fn get_room_entry_config(
+        &self,
+        row: isize,
+        col: isize,
+        base_energy: usize,
+        amphipod: char,
+    ) -> Vec<(isize, isize, usize)> {
+        let target_col = self.target_col(amphipod).unwrap() as isize;
+        if !self.can_enter_col(target_col as usize) {
+            return Vec::new();
+        }
+
+        let step = if col > target_col { -1 } else { 1 };
+        let col_iter = successors(Some(col + step), |&c| {
+            if c != target_col {
+                Some(c + step)
+            } else {
+                None
+            }
+        });
+
+        for new_col in col_iter {
+            if self.cell(row, new_col) != Some('.') {
+                return Vec::new();
+            }
+        }
+        for new_row in row + 1..self.cells.height() as isize - 1 {
+            if self.cell(new_row + 1, target_col) != Some('.') {
+                let energy = calculate_energy((1, col), (new_row, target_col), base_energy);
+                return vec![(new_row, target_col, energy)];
+            }
+        }
+
+        Vec::new()
+    }
+
+    fn target_col(&self, amphipod: char) -> Option<usize> {
+        match amphipod {
+            'A' => Some(3),
+            'B' => Some(5),
+            'C' => Some(7),
+            'D' => Some(9),
+            _ => None,
+        }
+    }
+
+    fn amphipod_energy(&self, amphipod: char) -> Option<usize> {
+        match amphipod {
+            'A' => Some(1),
+            'B' => Some(10),
+            'C' => Some(100),
+            'D' => Some(1000),
+            _ => None,
+        }
+    }
+
+    fn can_enter_col(&self, col: usize) -> bool {
+        for row in 2..self.cells.height() - 1 {
+            let col_amphipod = match col {
+                3 => 'A',
+                5 => 'B',
+                7 => 'C',
+                9 => 'D',
+                _ => return false,
+            };
+            if self
+                .cell(row as isize, col as isize)
+                .map(|c| c != '.' && c != col_amphipod)
+                .unwrap_or_default()
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn cell(&self, row: isize, col: isize) -> Option<char> {
+        self.cells.get(row, col).copied()
+    }
+
+    fn is_organised(&self) -> bool {
+        for row in 2..self.cells.height() - 1 {
+            for col in [3, 5, 7, 9] {
+                match (col, self.cell(row as isize, col)) {
+                    (3, Some('A')) | (5, Some('B')) | (7, Some('C')) | (9, Some('D')) => {}
+                    _ => {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+impl FromStr for Configuration {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl fmt::Display for Configuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+fn calculate_energy(start: (isize, isize), end: (isize, isize), step_cost: usize) -> usize {
+    ((end.0 - start.0).abs() + (end.1 - start.1).abs()) as usize * step_cost
+}
+
+fn find_minimum_energy(start: Configuration) -> Result<usize> {
+    let result = search::dijkstra(
+        start,
+        |config| config.get_adjacent_configurations(),
+        |config| config.is_organised(),
+    );
+    instrument::count("day23 configurations popped", result.stats.expanded as u64);
+    instrument::count("day23 configurations pushed", result.stats.pushed as u64);
+    result
+        .path
+        .map(|path| path.cost)
+        .ok_or_else(|| Error::unsolvable("Unable to find minimum energy."))
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Configuration;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        input.minimum_energy()
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        input.unfolded().minimum_energy()
+    }
+}
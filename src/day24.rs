@@ -0,0 +1,312 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, ParseError, Result};
+use crate::input::{self, InputLine};
+use crate::Solution;
+
+fn parse_parameters(input: &str) -> Result<Vec<ParameterGroup>> {
+    let mut parameter_groups = Vec::new();
+    let mut parameter_group = ParameterGroup::default();
+
+    for (i, line) in input::lines(input).enumerate() {
+        if i % 18 == 4 {
+            parameter_group.divisor = parse_line(line)?;
+        } else if i % 18 == 5 {
+            parameter_group.check = parse_line(line)?;
+        } else if i % 18 == 15 {
+            parameter_group.modifier = parse_line(line)?;
+            parameter_groups.push(parameter_group);
+            parameter_group = ParameterGroup::default();
+        }
+    }
+
+    Ok(parameter_groups)
+}
+
+fn parse_monad(input: &str) -> Result<Monad> {
+    let parameter_groups = parse_parameters(input)?;
+    if parameter_groups.len() != 14 {
+        let line = input.lines().count() + 1;
+        return Err(ParseError::new(line, 1, "Expected fourteen digit blocks.").into());
+    }
+
+    Ok(Monad::new(parameter_groups))
+}
+
+fn parse_line(line: InputLine) -> Result<isize> {
+    let value = line
+        .text()
+        .split(' ')
+        .nth(2)
+        .ok_or_else(|| line.error_at_end("Expected an instruction with two operands."))?;
+    value.parse::<isize>().map_err(|e| {
+        line.error_at(value, "Unable to parse integer.")
+            .with_source(e)
+            .into()
+    })
+}
+
+/// The three values that differ between the blocks of ALU instructions
+/// processing each digit of a model number.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParameterGroup {
+    pub divisor: isize,
+    pub check: isize,
+    pub modifier: isize,
+}
+
+impl ParameterGroup {
+    pub fn new(divisor: isize, check: isize, modifier: isize) -> Self {
+        Self {
+            divisor,
+            check,
+            modifier,
+        }
+    }
+}
+
+impl fmt::Display for ParameterGroup {
+    /// Writes the block of eighteen ALU instructions for one digit.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+             mul y x\nadd z y\n",
+            self.divisor, self.check, self.modifier
+        )
+    }
+}
+
+/// The MOdel Number Automatic Detector program, reduced to the parameters of
+/// each of its fourteen digit blocks. It's displayed as the full program.
+#[derive(Debug, Default, Clone)]
+pub struct Monad {
+    parameters: Vec<ParameterGroup>,
+    stack: Vec<(usize, isize)>,
+}
+
+impl Monad {
+    pub fn new(parameters: Vec<ParameterGroup>) -> Self {
+        let init_stack_size = parameters.len();
+        Self {
+            parameters,
+            stack: Vec::with_capacity(init_stack_size),
+        }
+    }
+
+    pub fn parameters(&self) -> &[ParameterGroup] {
+        &self.parameters
+    }
+
+    fn find_nearest(&mut self, init: &[isize]) -> Vec<isize> {
+        let mut input = init.to_vec();
+
+        'outer: loop {
+            self.stack.clear();
+            for (i, &value) in input.iter().enumerate() {
+                if let Some((index, value)) = self.test_input(i, value) {
+                    input[index] = value;
+                    continue 'outer;
+                }
+            }
+            break;
+        }
+
+        input
+    }
+
+    fn test_input(&mut self, index: usize, input: isize) -> Option<(usize, isize)> {
+        let parameters = &self.parameters[index];
+        let (prev_index, last) = self.stack.last().copied().unwrap_or_default();
+        let check = last + parameters.check;
+
+        let mut result = None;
+
+        if parameters.divisor == 26 {
+            self.stack.pop();
+        }
+        if check != input {
+            result = self.find_best_input(input, check, index, prev_index);
+            self.stack.push((index, input + parameters.modifier));
+        }
+
+        result
+    }
+
+    fn find_best_input(
+        &self,
+        input: isize,
+        check: isize,
+        index: usize,
+        prev_index: usize,
+    ) -> Option<(usize, isize)> {
+        let parameters = &self.parameters[index];
+        if parameters.divisor == 26 {
+            if !(1..=9).contains(&check) {
+                let optimal_input = input - parameters.check - self.parameters[prev_index].modifier;
+                Some((prev_index, optimal_input))
+            } else {
+                Some((index, check))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl PartialEq for Monad {
+    fn eq(&self, other: &Self) -> bool {
+        self.parameters == other.parameters
+    }
+}
+
+impl Eq for Monad {}
+
+impl FromStr for Monad {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_monad(s)
+    }
+}
+
+impl fmt::Display for Monad {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for parameters in self.parameters.iter() {
+            write!(f, "{}", parameters)?;
+        }
+        Ok(())
+    }
+}
+
+fn input_to_value(input: &[isize]) -> isize {
+    let mut value = 0;
+
+    for i in input {
+        value *= 10;
+        value += i;
+    }
+
+    value
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Monad;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let max = input.clone().find_nearest(&[9; 14]);
+        Ok(input_to_value(&max))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let min = input.clone().find_nearest(&[1; 14]);
+        Ok(input_to_value(&min))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::{self, Rng};
+
+    #[derive(Debug, Clone, Copy)]
+    enum Operand {
+        Register(usize),
+        Value(isize),
+    }
+
+    fn register(name: &str) -> usize {
+        (name.as_bytes()[0] - b'w') as usize
+    }
+
+    fn compile(program: &str) -> Vec<(&str, usize, Operand)> {
+        program
+            .lines()
+            .map(|line| {
+                let words = line.split(' ').collect::<Vec<_>>();
+                let operand = match words.get(2) {
+                    Some(w) => w
+                        .parse()
+                        .map_or_else(|_| Operand::Register(register(w)), Operand::Value),
+                    None => Operand::Value(0),
+                };
+                (words[0], register(words[1]), operand)
+            })
+            .collect()
+    }
+
+    /// Runs the ALU instruction by instruction, returning whether the model
+    /// number is accepted.
+    fn accepts(program: &[(&str, usize, Operand)], digits: &[isize]) -> bool {
+        let mut registers = [0_isize; 4];
+        let mut inputs = digits.iter();
+        for &(op, target, operand) in program {
+            let a = registers[target];
+            let b = match operand {
+                Operand::Register(r) => registers[r],
+                Operand::Value(v) => v,
+            };
+            registers[target] = match op {
+                "inp" => *inputs.next().unwrap(),
+                "add" => a + b,
+                "mul" => a * b,
+                "div" => a / b,
+                "mod" => a % b,
+                "eql" => (a == b) as isize,
+                _ => panic!("Unknown instruction '{}'.", op),
+            };
+        }
+        registers[3] == 0
+    }
+
+    #[test]
+    fn test_find_nearest_matches_brute_force() {
+        for (num_digits, seeds) in [(2, 0..20), (4, 0..20), (6, 0..3)] {
+            for seed in seeds {
+                let program = generate::monad(&mut Rng::new(seed), num_digits);
+                let instructions = compile(&program);
+
+                let accepted = (0..9_usize.pow(num_digits as u32))
+                    .map(|mut n| {
+                        let mut digits = vec![0; num_digits];
+                        for digit in digits.iter_mut().rev() {
+                            *digit = (n % 9) as isize + 1;
+                            n /= 9;
+                        }
+                        digits
+                    })
+                    .filter(|digits| accepts(&instructions, digits))
+                    .collect::<Vec<_>>();
+
+                let monad = Monad::new(parse_parameters(&program).unwrap());
+                let max = monad.clone().find_nearest(&vec![9; num_digits]);
+                let min = monad.clone().find_nearest(&vec![1; num_digits]);
+                assert_eq!(
+                    accepted.last(),
+                    Some(&max),
+                    "{} digits, seed {}",
+                    num_digits,
+                    seed
+                );
+                assert_eq!(
+                    accepted.first(),
+                    Some(&min),
+                    "{} digits, seed {}",
+                    num_digits,
+                    seed
+                );
+            }
+        }
+    }
+}
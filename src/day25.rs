@@ -0,0 +1,215 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::simulation::Simulation;
+use crate::snapshot::{self, Snapshot};
+use crate::viz::{self, Frame};
+use crate::Solution;
+
+fn parse_input(data: &str) -> Result<Map> {
+    let cells = Grid::parse(data, "'>', 'v' or '.'", |c| {
+        matches!(c, '>' | 'v' | '.').then_some(c)
+    })?;
+    Ok(Map::new(cells))
+}
+
+/// The sea floor: east-facing (`>`) and south-facing (`v`) sea cucumbers and
+/// empty locations (`.`). Indexing wraps around the edges.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Map {
+    cells: Grid<char>,
+}
+
+impl Map {
+    pub fn new(cells: Grid<char>) -> Self {
+        Self { cells }
+    }
+
+    pub fn cells(&self) -> &Grid<char> {
+        &self.cells
+    }
+
+    /// Moves the east-facing herd and then the south-facing herd, returning
+    /// how many sea cucumbers moved.
+    pub fn step(&mut self) -> usize {
+        step(self)
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.cells, |&c| match c {
+            '>' => 128,
+            'v' => 255,
+            _ => 0,
+        })
+    }
+
+    fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    fn height(&self) -> usize {
+        self.cells.height()
+    }
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+impl Index<[usize; 2]> for Map {
+    type Output = char;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        self.cells
+            .get_wrapping(index[0] as isize, index[1] as isize)
+    }
+}
+
+impl IndexMut<[usize; 2]> for Map {
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        self.cells
+            .get_wrapping_mut(index[0] as isize, index[1] as isize)
+    }
+}
+
+struct HerdIterSpec {
+    symbol: char,
+    make_coord: fn(usize, usize) -> [usize; 2],
+    next_coord: fn([usize; 2]) -> [usize; 2],
+    inner_max: usize,
+    outer_max: usize,
+}
+
+fn step(map: &mut Map) -> usize {
+    let mut num_moves = 0;
+
+    let herd_iter_specs: [HerdIterSpec; 2] = [
+        HerdIterSpec {
+            symbol: '>',
+            make_coord: |i, j| [i, j],
+            next_coord: |[i, j]| [i, j + 1],
+            inner_max: map.width(),
+            outer_max: map.height(),
+        },
+        HerdIterSpec {
+            symbol: 'v',
+            make_coord: |i, j| [j, i],
+            next_coord: |[i, j]| [i + 1, j],
+            inner_max: map.height(),
+            outer_max: map.width(),
+        },
+    ];
+
+    let mut vacated_coords = Vec::with_capacity(map.cells.len());
+
+    for spec in herd_iter_specs {
+        vacated_coords.clear();
+
+        for i in 0..spec.outer_max {
+            let mut iter = 0..spec.inner_max;
+            while let Some(j) = iter.next() {
+                let coords = (spec.make_coord)(i, j);
+                if map[coords] != spec.symbol {
+                    continue;
+                }
+
+                let new_coords = (spec.next_coord)(coords);
+                if map[new_coords] == '.' {
+                    num_moves += 1;
+                    map[new_coords] = map[coords];
+                    vacated_coords.push(coords);
+                    iter.next();
+                }
+            }
+        }
+
+        for coords in vacated_coords.iter().copied() {
+            map[coords] = '.';
+        }
+    }
+
+    num_moves
+}
+
+fn simulate(init: &Map) -> Result<usize> {
+    let (mut map, mut step_count) = match snapshot::resume(25, 1) {
+        Some(snapshot) => (snapshot.state::<Map>()?, snapshot.step()),
+        None => (init.clone(), 0),
+    };
+
+    viz::frame(|| map.frame());
+    while step(&mut map) > 0 {
+        step_count += 1;
+        viz::frame(|| map.frame());
+        snapshot::checkpoint(step_count, || Snapshot::new(25, 1, step_count, &map));
+    }
+
+    Ok(step_count + 1)
+}
+
+/// Moves both herds once per step until they're gridlocked.
+#[derive(Debug, Clone)]
+struct HerdSimulation {
+    map: Map,
+    gridlocked: bool,
+}
+
+impl Simulation for HerdSimulation {
+    fn step(&mut self) -> Option<String> {
+        let num_moves = self.map.step();
+        self.gridlocked = num_moves == 0;
+        self.gridlocked
+            .then(|| "Gridlock: no sea cucumber can move.".to_string())
+    }
+
+    fn is_finished(&self) -> bool {
+        self.gridlocked
+    }
+}
+
+impl fmt::Display for HerdSimulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        simulate(input)
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Self::Part2> {
+        Ok(String::new())
+    }
+
+    fn simulation(&self, input: &Self::Input) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(HerdSimulation {
+            map: input.clone(),
+            gridlocked: false,
+        }))
+    }
+}
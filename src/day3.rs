@@ -0,0 +1,232 @@
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::error::{Error, ParseError, Result};
+use crate::input::{self, InputLine};
+use crate::solution::Streaming;
+use crate::Solution;
+
+/// The submarine's diagnostic report: binary numbers that all share one width.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DiagnosticReport {
+    values: Vec<u16>,
+    num_bits: usize,
+}
+
+impl DiagnosticReport {
+    pub fn new(values: Vec<u16>, num_bits: usize) -> Result<Self> {
+        if num_bits > u16::BITS as usize {
+            return Err(Error::invalid_state(format!(
+                "Values wider than {} bits are not supported.",
+                u16::BITS
+            )));
+        }
+        if values
+            .iter()
+            .any(|&v| num_bits < u16::BITS as usize && v >> num_bits != 0)
+        {
+            return Err(Error::invalid_state(format!(
+                "Values must fit in {} bits.",
+                num_bits
+            )));
+        }
+        Ok(Self { values, num_bits })
+    }
+
+    pub fn values(&self) -> &[u16] {
+        &self.values
+    }
+
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+}
+
+impl FromStr for DiagnosticReport {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (values, num_bits) = parse_input(s)?;
+        Ok(Self { values, num_bits })
+    }
+}
+
+impl fmt::Display for DiagnosticReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for value in self.values.iter() {
+            writeln!(f, "{:0width$b}", value, width = self.num_bits)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_num_bits(first_line: Option<InputLine>) -> Result<usize> {
+    let num_bits = if let Some(l) = first_line {
+        l.text().len()
+    } else {
+        return Err(ParseError::new(1, 1, "Input file is empty.").into());
+    };
+    if num_bits > u16::BITS as usize {
+        let message = format!("Values wider than {} bits are not supported.", u16::BITS);
+        return Err(ParseError::new(1, 1, message).into());
+    }
+    Ok(num_bits)
+}
+
+fn parse_value(line: InputLine, num_bits: usize) -> Result<u16> {
+    if line.text().len() != num_bits {
+        return Err(line
+            .error(format!("Expected a value with {} bits.", num_bits))
+            .into());
+    }
+    u16::from_str_radix(line.text(), 2).map_err(|e| {
+        line.error("Unable to parse binary value.")
+            .with_source(e)
+            .into()
+    })
+}
+
+fn parse_input(data: &str) -> Result<(Vec<u16>, usize)> {
+    let num_bits = parse_num_bits(input::lines(data).next())?;
+    let values = input::lines(data)
+        .map(|line| parse_value(line, num_bits))
+        .collect::<Result<Vec<u16>>>()?;
+
+    Ok((values, num_bits))
+}
+
+/// How many times each value occurs, indexed by value, which takes bounded
+/// space however long the report is.
+fn count_values(values: &[u16], num_bits: usize) -> Vec<usize> {
+    let mut counts = vec![0; 1 << num_bits];
+    for &value in values {
+        counts[value as usize] += 1;
+    }
+    counts
+}
+
+/// The values that occur, each with its number of occurrences.
+fn tally(counts: &[usize]) -> Vec<(u16, usize)> {
+    counts
+        .iter()
+        .enumerate()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(value, &count)| (value as u16, count))
+        .collect()
+}
+
+fn calculate_bit_ratios(tally: &[(u16, usize)], num_bits: usize) -> Result<Vec<f64>> {
+    let mut counts = vec![0; num_bits];
+    let mut total = 0;
+
+    for &(value, occurrences) in tally {
+        total += occurrences;
+        for (i, count) in counts.iter_mut().enumerate() {
+            if ((1 << i) & value) > 0 {
+                *count += occurrences;
+            }
+        }
+    }
+
+    let ratios = counts.iter().map(|&c| c as f64 / total as f64).collect();
+
+    Ok(ratios)
+}
+
+fn calculate_rate(tally: &[(u16, usize)], num_bits: usize, filter: fn(f64) -> bool) -> Result<u16> {
+    let ratios = calculate_bit_ratios(tally, num_bits)?;
+    let mut rate = 0u16;
+
+    for (i, &r) in ratios.iter().enumerate() {
+        if filter(r) {
+            rate |= 1 << i;
+        }
+    }
+
+    Ok(rate)
+}
+
+fn calcuate_rating(
+    tally: &[(u16, usize)],
+    num_bits: usize,
+    filter: fn(f64) -> bool,
+) -> Result<u16> {
+    let mut filtered_numbers = tally.to_vec();
+
+    for i in (0..num_bits).rev() {
+        let ratios = calculate_bit_ratios(&filtered_numbers, num_bits)?;
+        let mask = 1 << i;
+        let expected = if filter(ratios[i]) { mask } else { 0 };
+
+        filtered_numbers.retain(|&(v, _)| v & mask == expected);
+
+        if let [(value, 1)] = filtered_numbers[..] {
+            return Ok(value);
+        }
+    }
+
+    Err(Error::unsolvable(
+        "Multiple numbers remaining after filterering.",
+    ))
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = DiagnosticReport;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let num_bits = input.num_bits();
+        let tally = tally(&count_values(input.values(), num_bits));
+        power_consumption(&tally, num_bits)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let num_bits = input.num_bits();
+        let tally = tally(&count_values(input.values(), num_bits));
+        life_support_rating(&tally, num_bits)
+    }
+}
+
+fn power_consumption(tally: &[(u16, usize)], num_bits: usize) -> Result<u32> {
+    let gamma_rate = calculate_rate(tally, num_bits, |r| r > 0.5)?;
+    let epsilon_rate = calculate_rate(tally, num_bits, |r| r <= 0.5)?;
+
+    Ok(gamma_rate as u32 * epsilon_rate as u32)
+}
+
+fn life_support_rating(tally: &[(u16, usize)], num_bits: usize) -> Result<u32> {
+    let o2_gen_rating = calcuate_rating(tally, num_bits, |r| r >= 0.5)?;
+    let co2_scrub_rating = calcuate_rating(tally, num_bits, |r| r < 0.5)?;
+
+    Ok(o2_gen_rating as u32 * co2_scrub_rating as u32)
+}
+
+impl Streaming for Day3 {
+    fn solve_stream(&self, reader: &mut dyn BufRead) -> Result<(u32, u32)> {
+        let mut lines = input::Reader::lines(reader);
+        let first = lines.next_record()?;
+        let num_bits = parse_num_bits(first)?;
+        let mut counts = vec![0; 1 << num_bits];
+        if let Some(line) = first {
+            counts[parse_value(line, num_bits)? as usize] += 1;
+        }
+        while let Some(line) = lines.next_record()? {
+            counts[parse_value(line, num_bits)? as usize] += 1;
+        }
+
+        let tally = tally(&counts);
+        Ok((
+            power_consumption(&tally, num_bits)?,
+            life_support_rating(&tally, num_bits)?,
+        ))
+    }
+}
@@ -0,0 +1,295 @@
+use core::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, ParseError, Result};
+use crate::grid::Grid;
+use crate::input::{self, InputLine};
+use crate::simulation::Simulation;
+use crate::Solution;
+
+fn parse_values<'a>(
+    line: InputLine<'a>,
+    values: impl Iterator<Item = &'a str>,
+) -> Result<Vec<i32>> {
+    values
+        .map(|s| {
+            s.parse::<i32>().map_err(|e| {
+                line.error_at(s, "Unable to parse integer.")
+                    .with_source(e)
+                    .into()
+            })
+        })
+        .collect()
+}
+
+fn parse_board<'a>(lines: impl IntoIterator<Item = InputLine<'a>>) -> Result<Board> {
+    let mut values = Vec::new();
+    let mut width: usize = 0;
+    let mut height: usize = 0;
+
+    for line in lines {
+        let row_values = parse_values(line, line.text().split_whitespace())?;
+        if height > 0 && row_values.len() != width {
+            return Err(line
+                .error(format!("Expected {} values in board row.", width))
+                .into());
+        }
+        height += 1;
+        values.extend_from_slice(&row_values);
+        width = row_values.len();
+    }
+
+    Ok(Board::new(Grid::from_vec(values, width, height)))
+}
+
+fn parse_input(input: &str) -> Result<Bingo> {
+    let mut line_iter = input::lines(input);
+    let numbers = if let Some(line) = line_iter.next() {
+        parse_values(line, line.text().split(','))?
+    } else {
+        return Err(ParseError::new(1, 1, "Input file is empty.").into());
+    };
+
+    line_iter.next();
+
+    let mut boards = Vec::new();
+    let mut lines = Vec::new();
+
+    for line in line_iter {
+        if line.text().is_empty() {
+            boards.push(parse_board(lines.drain(..))?);
+        } else {
+            lines.push(line);
+        }
+    }
+
+    if !lines.is_empty() {
+        boards.push(parse_board(lines)?);
+    }
+
+    Ok(Bingo::new(numbers, boards))
+}
+
+/// The numbers drawn in a game of bingo and the boards playing it.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Bingo {
+    numbers: Vec<i32>,
+    boards: Vec<Board>,
+}
+
+impl Bingo {
+    pub fn new(numbers: Vec<i32>, boards: Vec<Board>) -> Self {
+        Self { numbers, boards }
+    }
+
+    pub fn numbers(&self) -> &[i32] {
+        &self.numbers
+    }
+
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+}
+
+impl FromStr for Bingo {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl fmt::Display for Bingo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers = self
+            .numbers
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", numbers.join(","))?;
+        for board in self.boards.iter() {
+            writeln!(f)?;
+            write!(f, "{}", board)?;
+        }
+        Ok(())
+    }
+}
+
+/// A bingo board, along with which of its values have been marked. Marked
+/// values are displayed as `x`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Board {
+    values: Grid<i32>,
+    markers: Grid<bool>,
+}
+
+impl Board {
+    pub fn new(values: Grid<i32>) -> Self {
+        Self {
+            markers: values.map(|_| false),
+            values,
+        }
+    }
+
+    pub fn values(&self) -> &Grid<i32> {
+        &self.values
+    }
+
+    fn wins(&self) -> bool {
+        self.markers.rows().any(|row| row.iter().all(|v| *v))
+            || self.markers.columns().any(|mut col| col.all(|v| *v))
+    }
+
+    fn mark(&mut self, value: i32) {
+        for (marker, &v) in self.markers.iter_mut().zip(self.values.iter()) {
+            if v == value {
+                *marker = true;
+            }
+        }
+    }
+
+    fn sum_unmarked_values(&self) -> i32 {
+        self.values
+            .iter()
+            .zip(self.markers.iter())
+            .filter(|(_, &marked)| !marked)
+            .map(|(value, _)| value)
+            .sum()
+    }
+}
+
+impl FromStr for Board {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_board(input::lines(s))
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for position in self.values.positions() {
+            if self.markers[position] {
+                write!(f, "  x")?;
+            } else {
+                write!(f, " {:>2}", self.values[position])?;
+            }
+            if position.1 + 1 == self.values.width() {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn compute_ordered_scores(numbers: &[i32], boards: &[Board]) -> Vec<i32> {
+    let mut boards = boards.to_vec();
+    let mut ordered_scores = Vec::new();
+    let mut board_is_winning = vec![false; boards.len()];
+
+    for &number in numbers {
+        for (i, board) in boards.iter_mut().enumerate() {
+            board.mark(number);
+            if board.wins() && !board_is_winning[i] {
+                let score = board.sum_unmarked_values() * number;
+                ordered_scores.push(score);
+                board_is_winning[i] = true;
+            }
+        }
+    }
+
+    ordered_scores
+}
+
+/// Draws one number per step, announcing each board as it wins.
+#[derive(Debug, Clone)]
+struct BingoSimulation {
+    bingo: Bingo,
+    drawn: usize,
+    board_is_winning: Vec<bool>,
+}
+
+impl BingoSimulation {
+    fn new(bingo: Bingo) -> Self {
+        Self {
+            board_is_winning: vec![false; bingo.boards.len()],
+            bingo,
+            drawn: 0,
+        }
+    }
+}
+
+impl Simulation for BingoSimulation {
+    fn step(&mut self) -> Option<String> {
+        let number = self.bingo.numbers[self.drawn];
+        self.drawn += 1;
+
+        let mut events = Vec::new();
+        for (i, board) in self.bingo.boards.iter_mut().enumerate() {
+            board.mark(number);
+            if board.wins() && !self.board_is_winning[i] {
+                let score = board.sum_unmarked_values() * number;
+                events.push(format!("board {} wins with score {}", i + 1, score));
+                self.board_is_winning[i] = true;
+            }
+        }
+        (!events.is_empty()).then(|| format!("Drew {}; {}.", number, events.join(", ")))
+    }
+
+    fn is_finished(&self) -> bool {
+        self.drawn == self.bingo.numbers.len()
+    }
+}
+
+impl fmt::Display for BingoSimulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let drawn = self.bingo.numbers[..self.drawn]
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        writeln!(f, "Drawn: {}", drawn.join(","))?;
+        for (i, board) in self.bingo.boards.iter().enumerate() {
+            writeln!(f)?;
+            if self.board_is_winning[i] {
+                writeln!(f, "Board {} (won)", i + 1)?;
+            } else {
+                writeln!(f, "Board {}", i + 1)?;
+            }
+            write!(f, "{}", board)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Bingo;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let ordered_scores = compute_ordered_scores(input.numbers(), input.boards());
+        let first = ordered_scores
+            .first()
+            .ok_or_else(|| Error::unsolvable("No board wins."))?;
+        Ok(*first)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let ordered_scores = compute_ordered_scores(input.numbers(), input.boards());
+        let last = ordered_scores
+            .last()
+            .ok_or_else(|| Error::unsolvable("No board wins."))?;
+        Ok(*last)
+    }
+
+    fn simulation(&self, input: &Self::Input) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(BingoSimulation::new(input.clone())))
+    }
+}
@@ -0,0 +1,303 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, ParseError, Result};
+use crate::grid::Grid;
+use crate::input::{self, InputLine};
+use crate::Solution;
+
+/// A position on the ocean floor, written as `x,y`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_point(InputLine::new(1, s), s)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct PointIter {
+    current: Point,
+    end: Point,
+    step: (i32, i32),
+}
+
+impl PointIter {
+    fn new(begin: Point, end: Point) -> Self {
+        assert!(
+            begin.x == end.x
+                || begin.y == end.y
+                || (end.x as i32 - begin.x as i32).abs() == (end.y as i32 - begin.y as i32).abs()
+        );
+
+        let step = match (begin.x.cmp(&end.x), begin.y.cmp(&end.y)) {
+            (Ordering::Less, Ordering::Less) => (1, 1),
+            (Ordering::Less, Ordering::Equal) => (1, 0),
+            (Ordering::Less, Ordering::Greater) => (1, -1),
+            (Ordering::Equal, Ordering::Less) => (0, 1),
+            (Ordering::Equal, Ordering::Equal) => (0, 0),
+            (Ordering::Equal, Ordering::Greater) => (0, -1),
+            (Ordering::Greater, Ordering::Less) => (-1, 1),
+            (Ordering::Greater, Ordering::Equal) => (-1, 0),
+            (Ordering::Greater, Ordering::Greater) => (-1, -1),
+        };
+
+        let end = Point::new(
+            (end.x as i32 + step.0) as usize,
+            (end.y as i32 + step.1) as usize,
+        );
+
+        Self {
+            current: begin,
+            end,
+            step,
+        }
+    }
+}
+
+impl Iterator for PointIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.end {
+            None
+        } else {
+            let ret = self.current;
+            self.current.x = (self.current.x as i32 + self.step.0) as usize;
+            self.current.y = (self.current.y as i32 + self.step.1) as usize;
+            Some(ret)
+        }
+    }
+}
+
+/// A line of hydrothermal vents, written as `x1,y1 -> x2,y2`. Lines are
+/// always horizontal, vertical or at 45 degrees.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    pub fn new(start: Point, end: Point) -> Result<Self> {
+        let dx = (end.x as i32 - start.x as i32).abs();
+        let dy = (end.y as i32 - start.y as i32).abs();
+        if dx != 0 && dy != 0 && dx != dy {
+            return Err(Error::invalid_state(
+                "Line is not horizontal, vertical or diagonal.",
+            ));
+        }
+        Ok(Self { start, end })
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    pub fn is_horizontal_or_vertical(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        PointIter::new(self.start, self.end)
+    }
+}
+
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_line(InputLine::new(1, s))
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.start, self.end)
+    }
+}
+
+/// Every line of vents in the nearby field.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Vents {
+    lines: Vec<Line>,
+}
+
+impl Vents {
+    pub fn new(lines: Vec<Line>) -> Self {
+        Self { lines }
+    }
+
+    pub fn lines(&self) -> &[Line] {
+        &self.lines
+    }
+
+    /// The side of the smallest square diagram, anchored at `0,0`, that
+    /// holds every line.
+    pub fn grid_size(&self) -> usize {
+        self.lines
+            .iter()
+            .map(|l| {
+                let values = [l.start.x, l.start.y, l.end.x, l.end.y];
+                values.iter().max().copied().unwrap_or_default() + 1
+            })
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl FromStr for Vents {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_input(s)
+    }
+}
+
+impl fmt::Display for Vents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines.iter() {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_coord(line: InputLine, coord: Option<&str>, end: &str) -> Result<usize> {
+    let coord = coord.ok_or_else(|| {
+        line.error_at(end, "Expected a coordinate.")
+            .with_expected("a point like '3,4'")
+    })?;
+    let value = coord.parse::<usize>().map_err(|e| {
+        line.error_at(coord, "Unable to parse coordinate.")
+            .with_expected("a non-negative integer")
+            .with_source(e)
+    })?;
+    Ok(value)
+}
+
+fn parse_point(line: InputLine, point_spec: &str) -> Result<Point> {
+    let end = &point_spec[point_spec.len()..];
+    let mut iter = point_spec.split(',');
+    let x = parse_coord(line, iter.next(), end)?;
+    let y = parse_coord(line, iter.next(), end)?;
+    Ok(Point::new(x, y))
+}
+
+fn parse_line(line: InputLine) -> Result<Line> {
+    let mut point_iter = line.text().split(" -> ");
+    let start = parse_point(line, point_iter.next().unwrap_or_default())?;
+    let second = point_iter.next().ok_or_else(|| {
+        line.error_at_end("Expected ' -> ' followed by a point.")
+            .with_expected("a line like '0,9 -> 5,9'")
+    })?;
+    let end = parse_point(line, second)?;
+
+    Line::new(start, end).map_err(|_| {
+        line.error_at(line.text(), "Line is not horizontal, vertical or diagonal.")
+            .with_expected("equal x, equal y or a 45 degree slope")
+            .into()
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vents> {
+    let lines = input::lines(input)
+        .map(parse_line)
+        .collect::<Result<Vec<Line>>>()?;
+    if lines.is_empty() {
+        return Err(ParseError::new(1, 1, "Input is empty.").into());
+    }
+
+    Ok(Vents::new(lines))
+}
+
+#[derive(Debug, Default)]
+struct Diagram {
+    counts: Grid<u32>,
+}
+
+impl Diagram {
+    fn new(size: usize) -> Self {
+        Self {
+            counts: Grid::new(size, size, 0),
+        }
+    }
+
+    fn mark_point(&mut self, point: Point) {
+        self.counts[(point.y, point.x)] += 1
+    }
+
+    fn mark_line(&mut self, line: &Line) {
+        for p in line.points() {
+            self.mark_point(p);
+        }
+    }
+
+    fn num_intersections(&self) -> usize {
+        self.counts.iter().filter(|&&c| c > 1).count()
+    }
+}
+
+impl fmt::Display for Diagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.counts)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vents;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let mut grid = Diagram::new(input.grid_size());
+        for line in input
+            .lines()
+            .iter()
+            .filter(|l| l.is_horizontal_or_vertical())
+        {
+            grid.mark_line(line);
+        }
+
+        Ok(grid.num_intersections())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let mut grid = Diagram::new(input.grid_size());
+        for line in input.lines() {
+            grid.mark_line(line);
+        }
+
+        Ok(grid.num_intersections())
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::config;
+use crate::error::{Error, ParseError, Result};
+use crate::input::{self, InputLine};
+use crate::solution::Streaming;
+use crate::Solution;
+
+/// A school of lanternfish, counted by the number of days left on each
+/// fish's spawning timer. It's written as a comma separated list of timers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct School {
+    counts: [usize; 9],
+}
+
+impl School {
+    pub fn new(counts: [usize; 9]) -> Self {
+        Self { counts }
+    }
+
+    /// How many fish have each timer value from 0 to 8.
+    pub fn counts(&self) -> [usize; 9] {
+        self.counts
+    }
+
+    pub fn len(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The school as it will be after `days` days, or an error if it grows
+    /// too large to count.
+    pub fn after(&self, days: usize) -> Result<Self> {
+        simulate(self.counts, days).map(Self::new).ok_or_else(|| {
+            Error::unsolvable(format!("Too many fish to count after {} days.", days))
+        })
+    }
+}
+
+impl FromStr for School {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let line = input::lines(s)
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "Input file is empty."))?;
+
+        let mut bucketed_ages = [0_usize; 9];
+
+        for s in line.text().split(',') {
+            add_age(&mut bucketed_ages, line, s)?;
+        }
+
+        Ok(Self::new(bucketed_ages))
+    }
+}
+
+fn add_age(bucketed_ages: &mut [usize; 9], line: InputLine, s: &str) -> Result<()> {
+    let age = s
+        .parse::<usize>()
+        .map_err(|e| line.error_at(s, "Unable to parse integer.").with_source(e))?;
+    let bucket = bucketed_ages
+        .get_mut(age)
+        .ok_or_else(|| line.error_at(s, "Expected an age between 0 and 8."))?;
+    *bucket += 1;
+    Ok(())
+}
+
+impl fmt::Display for School {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let timers = self
+            .counts
+            .iter()
+            .enumerate()
+            .flat_map(|(timer, &count)| std::iter::repeat_n(timer.to_string(), count))
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", timers.join(","))
+    }
+}
+
+fn simulate(ages: [usize; 9], days: usize) -> Option<[usize; 9]> {
+    let mut ages = ages;
+    // No single count can overflow while the whole school still fits.
+    let mut total = ages.iter().sum::<usize>();
+
+    for _ in 0..days {
+        let mut new_ages = [0; 9];
+
+        new_ages[..8].copy_from_slice(&ages[1..]);
+        new_ages[6] += ages[0];
+        new_ages[8] = ages[0];
+        total = total.checked_add(ages[0])?;
+
+        ages = new_ages;
+    }
+
+    Some(ages)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day6 {
+    part1_days: usize,
+    part2_days: usize,
+}
+
+impl Default for Day6 {
+    fn default() -> Self {
+        Self {
+            part1_days: 80,
+            part2_days: 256,
+        }
+    }
+}
+
+impl Solution for Day6 {
+    type Input = School;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        Ok(input.after(self.part1_days)?.len())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        Ok(input.after(self.part2_days)?.len())
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "part1_days" => self.part1_days = config::parse_value(key, value)?,
+            "part2_days" => self.part2_days = config::parse_value(key, value)?,
+            _ => return Err(config::unknown_key(key)),
+        }
+        Ok(())
+    }
+}
+
+impl Streaming for Day6 {
+    fn solve_stream(&self, reader: &mut dyn BufRead) -> Result<(usize, usize)> {
+        let mut fields = input::Reader::fields(reader, b',');
+        let mut bucketed_ages = [0_usize; 9];
+        let mut empty = true;
+        // Like parsing, only the first line counts.
+        while let Some(field) = fields.next_record()? {
+            if field.number() > 1 {
+                break;
+            }
+            add_age(&mut bucketed_ages, field, field.text())?;
+            empty = false;
+        }
+        if empty {
+            return Err(ParseError::new(1, 1, "Input file is empty.").into());
+        }
+
+        let school = School::new(bucketed_ages);
+        Ok((self.part1(&school)?, self.part2(&school)?))
+    }
+}
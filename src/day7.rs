@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::error::{Error, ParseError, Result};
+use crate::input::{self, InputLine};
+use crate::solution::Streaming;
+use crate::Solution;
+
+const GOLDEN_RATIO: f64 = 1.618033988749895;
+
+/// The horizontal positions of the crab submarines, counted by position and
+/// written as a comma separated list.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Crabs {
+    positions: HashMap<i32, i32>,
+}
+
+impl Crabs {
+    pub fn new(positions: impl IntoIterator<Item = i32>) -> Self {
+        let mut bucketed_positions = HashMap::new();
+        for p in positions {
+            let entry: &mut i32 = bucketed_positions.entry(p).or_default();
+            *entry += 1;
+        }
+        Self {
+            positions: bucketed_positions,
+        }
+    }
+
+    /// How many crabs are at each occupied position.
+    pub fn positions(&self) -> &HashMap<i32, i32> {
+        &self.positions
+    }
+}
+
+impl FromStr for Crabs {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_data(s)
+    }
+}
+
+impl fmt::Display for Crabs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut positions = self.positions.iter().collect::<Vec<_>>();
+        positions.sort_unstable();
+        let positions = positions
+            .into_iter()
+            .flat_map(|(&p, &count)| std::iter::repeat_n(p.to_string(), count as usize))
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", positions.join(","))
+    }
+}
+
+fn parse_data(data: &str) -> Result<Crabs> {
+    let line = input::lines(data)
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "Input file is empty."))?;
+    let positions = line
+        .text()
+        .split(',')
+        .map(|s| parse_position(line, s))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Crabs::new(positions))
+}
+
+fn parse_position(line: InputLine, s: &str) -> Result<i32> {
+    s.parse::<i32>().map_err(|e| {
+        line.error_at(s, "Unable to parse integer.")
+            .with_source(e)
+            .into()
+    })
+}
+
+fn compute_fuel_const(positions: &HashMap<i32, i32>, position: f64) -> f64 {
+    positions
+        .iter()
+        .map(|(&k, &v)| (k as f64 - position).abs() * v as f64)
+        .sum()
+}
+
+fn compute_fuel_monotonic(positions: &HashMap<i32, i32>, position: f64) -> f64 {
+    positions
+        .iter()
+        .map(|(&k, &v)| {
+            let num_steps = (k as f64 - position).abs();
+            let usage = num_steps * (num_steps + 1.0) / 2.0;
+            usage * v as f64
+        })
+        .sum()
+}
+
+/// The fuel one crab burns moving `steps` positions in part 1.
+fn constant_step_fuel(steps: i64) -> Option<i64> {
+    Some(steps)
+}
+
+/// The fuel one crab burns moving `steps` positions in part 2, where each
+/// step costs one more than the last.
+fn monotonic_step_fuel(steps: i64) -> Option<i64> {
+    steps.checked_mul(steps + 1).map(|fuel| fuel / 2)
+}
+
+/// The exact fuel every crab burns to reach `target`.
+fn total_fuel(
+    positions: &HashMap<i32, i32>,
+    target: i32,
+    step_fuel: fn(i64) -> Option<i64>,
+) -> Result<i64> {
+    positions.iter().try_fold(0_i64, |total, (&p, &count)| {
+        step_fuel((p as i64 - target as i64).abs())
+            .and_then(|fuel| fuel.checked_mul(count as i64))
+            .and_then(|fuel| total.checked_add(fuel))
+            .ok_or_else(|| Error::unsolvable("Too much fuel to count."))
+    })
+}
+
+/// Narrows down the cheapest position using the continuous `compute_fuel`,
+/// then counts the fuel to the remaining candidates exactly with `step_fuel`.
+fn compute_optimal_fuel_usage(
+    positions: &HashMap<i32, i32>,
+    compute_fuel: fn(&HashMap<i32, i32>, f64) -> f64,
+    step_fuel: fn(i64) -> Option<i64>,
+) -> Result<(i32, i64)> {
+    let min = positions
+        .keys()
+        .copied()
+        .min()
+        .ok_or_else(|| Error::unsolvable("Input data empty."))?;
+    let max = positions.keys().copied().max().unwrap_or(min);
+
+    let mut lower = min as f64;
+    let mut upper = max as f64;
+
+    // Far from zero an f64 can't resolve much below 1, so stop there and
+    // try the few whole positions that are left.
+    while upper - lower > 1.0 {
+        let offset = (upper - lower) / GOLDEN_RATIO;
+        let new_lower = upper - offset;
+        let new_upper = lower + offset;
+        let new_fuel_lower = compute_fuel(positions, new_lower);
+        let new_fuel_upper = compute_fuel(positions, new_upper);
+
+        if new_fuel_lower < new_fuel_upper {
+            upper = new_upper;
+        } else {
+            lower = new_lower;
+        }
+    }
+
+    // The search stays between the outermost crabs, so these can't saturate.
+    let mut best = None;
+    for position in lower.floor() as i32..=upper.ceil() as i32 {
+        let fuel = total_fuel(positions, position, step_fuel)?;
+        if best.is_none_or(|(_, best_fuel)| fuel < best_fuel) {
+            best = Some((position, fuel));
+        }
+    }
+    Ok(best.unwrap())
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Crabs;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let (_, fuel) =
+            compute_optimal_fuel_usage(input.positions(), compute_fuel_const, constant_step_fuel)?;
+        Ok(fuel)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let (_, fuel) = compute_optimal_fuel_usage(
+            input.positions(),
+            compute_fuel_monotonic,
+            monotonic_step_fuel,
+        )?;
+        Ok(fuel)
+    }
+}
+
+impl Streaming for Day7 {
+    /// Crabs are counted by position as they are read, so memory grows with
+    /// the number of distinct positions rather than the number of crabs.
+    fn solve_stream(&self, reader: &mut dyn BufRead) -> Result<(i64, i64)> {
+        let mut fields = input::Reader::fields(reader, b',');
+        let mut positions = HashMap::new();
+        let mut empty = true;
+        while let Some(field) = fields.next_record()? {
+            if field.number() > 1 {
+                break;
+            }
+            *positions
+                .entry(parse_position(field, field.text())?)
+                .or_default() += 1;
+            empty = false;
+        }
+        if empty {
+            return Err(ParseError::new(1, 1, "Input file is empty.").into());
+        }
+
+        let crabs = Crabs { positions };
+        Ok((self.part1(&crabs)?, self.part2(&crabs)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    /// Tries every position between the outermost crabs, where moving `d`
+    /// steps costs `step_cost(d)`.
+    fn brute_force_fuel(positions: &HashMap<i32, i32>, step_cost: fn(i64) -> i64) -> i64 {
+        let min = *positions.keys().min().unwrap();
+        let max = *positions.keys().max().unwrap();
+        (min..=max)
+            .map(|target| {
+                positions
+                    .iter()
+                    .map(|(&p, &count)| step_cost((p as i64 - target as i64).abs()) * count as i64)
+                    .sum()
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_optimal_fuel_matches_brute_force() {
+        for seed in 0..300 {
+            let mut rng = Rng::new(seed);
+            let mut positions = HashMap::new();
+            for _ in 0..rng.range(1, 20) {
+                *positions.entry(rng.range(0, 60) as i32).or_default() += 1;
+            }
+
+            let (_, fuel) =
+                compute_optimal_fuel_usage(&positions, compute_fuel_const, constant_step_fuel)
+                    .unwrap();
+            let expected = brute_force_fuel(&positions, |d| d);
+            assert_eq!(expected, fuel, "seed {}, positions {:?}", seed, positions);
+
+            let (_, fuel) =
+                compute_optimal_fuel_usage(&positions, compute_fuel_monotonic, monotonic_step_fuel)
+                    .unwrap();
+            let expected = brute_force_fuel(&positions, |d| d * (d + 1) / 2);
+            assert_eq!(expected, fuel, "seed {}, positions {:?}", seed, positions);
+        }
+    }
+}
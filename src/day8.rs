@@ -0,0 +1,247 @@
+use std::fmt;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::input::{self, InputLine};
+use crate::Solution;
+
+//   | a b c d e f g |
+// 2 |     1     1   | 0010010
+// 3 | 7   7     7   | 1010010
+// 4 |   4 4 4   4   | 0111010
+// 5 | 2   2 2 2   2 | 1011101
+// 5 | 3   3 3   3 3 | 1011011
+// 5 | 5 5   5   5 5 | 1101011
+// 6 | 6 6   6 6 6 6 | 1101111
+// 6 | 0 0 0   0 0 0 | 1110111
+// 6 | 9 9 9 9   9 9 | 1111011
+// 7 | 8 8 8 8 8 8 8 | 1111111
+
+fn sort_string(string: &str) -> String {
+    let mut chars = string.chars().collect::<Vec<_>>();
+    chars.sort();
+    String::from_iter(chars)
+}
+
+fn find_segment_pos(mapping: &str, segments: &[String], char_count: usize) -> Result<usize> {
+    segments
+        .iter()
+        .enumerate()
+        .filter(|&(_, s)| mapping.chars().filter(|&c| s.contains(c)).count() == char_count)
+        .map(|(i, _)| i)
+        .next()
+        .ok_or_else(|| Error::unsolvable("Missing segment in input."))
+}
+
+fn extract_digit_map(patterns: &[String]) -> Result<Vec<String>> {
+    let mut digit_descriptors: Vec<_> = patterns.iter().map(|p| sort_string(p)).collect();
+    digit_descriptors.sort_by_key(|s1| s1.len());
+
+    let mut mappings = vec!["".to_string(); 10];
+
+    mappings[1] = digit_descriptors.remove(0);
+    mappings[7] = digit_descriptors.remove(0);
+    mappings[4] = digit_descriptors.remove(0);
+    mappings[8] = digit_descriptors
+        .pop()
+        .ok_or_else(|| Error::unsolvable("Not enough digits in input."))?;
+
+    let mut five_digit_segments = digit_descriptors
+        .iter()
+        .filter(|s| s.len() == 5)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let pos = find_segment_pos(&mappings[1], &five_digit_segments, 2)?;
+    mappings[3] = five_digit_segments.remove(pos);
+    let pos = find_segment_pos(&mappings[4], &five_digit_segments, 2)?;
+    mappings[2] = five_digit_segments.remove(pos);
+    mappings[5] = five_digit_segments
+        .pop()
+        .ok_or_else(|| Error::unsolvable("Missing segment in input."))?;
+
+    let mut six_digit_segments = digit_descriptors
+        .iter()
+        .filter(|s| s.len() == 6)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let pos = find_segment_pos(&mappings[1], &six_digit_segments, 1)?;
+    mappings[6] = six_digit_segments.remove(pos);
+    let pos = find_segment_pos(&mappings[4], &six_digit_segments, 3)?;
+    mappings[0] = six_digit_segments.remove(pos);
+    mappings[9] = six_digit_segments
+        .pop()
+        .ok_or_else(|| Error::unsolvable("Missing segment in input."))?;
+
+    Ok(mappings)
+}
+
+/// One line of notes: the ten unique signal patterns seen on a display and
+/// the four digits of its output value, written `patterns | outputs`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Entry {
+    patterns: Vec<String>,
+    outputs: Vec<String>,
+}
+
+impl Entry {
+    pub fn new(patterns: Vec<String>, outputs: Vec<String>) -> Result<Self> {
+        if patterns.len() != 10 {
+            return Err(Error::invalid_state("Expected ten signal patterns."));
+        }
+        if outputs.len() != 4 {
+            return Err(Error::invalid_state("Expected four output digits."));
+        }
+        let outputs = outputs.iter().map(|o| sort_string(o)).collect();
+        Ok(Self { patterns, outputs })
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// The output digits, with each digit's segments sorted alphabetically.
+    pub fn outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    /// Works out the wiring from the signal patterns and reads the output
+    /// value.
+    pub fn decode(&self) -> Result<usize> {
+        decode_entry(self)
+    }
+}
+
+impl FromStr for Entry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_line(InputLine::new(1, s))
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} | {}",
+            self.patterns.join(" "),
+            self.outputs.join(" ")
+        )
+    }
+}
+
+/// The notes taken on every malfunctioning display.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Notes {
+    entries: Vec<Entry>,
+}
+
+impl Notes {
+    pub fn new(entries: Vec<Entry>) -> Self {
+        Self { entries }
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+}
+
+impl FromStr for Notes {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let entries = input::lines(s).map(parse_line).collect::<Result<_>>()?;
+        Ok(Self::new(entries))
+    }
+}
+
+impl fmt::Display for Notes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in self.entries.iter() {
+            writeln!(f, "{}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_line(line: InputLine) -> Result<Entry> {
+    let mut split_iter = line.text().split(" | ");
+    let patterns = split_iter.next().unwrap_or_default();
+    if patterns.split_whitespace().count() != 10 {
+        return Err(line.error("Expected ten signal patterns.").into());
+    }
+
+    let outputs = split_iter
+        .next()
+        .ok_or_else(|| line.error_at_end("Expected ' | ' followed by output digits."))?;
+    if outputs.split_whitespace().count() != 4 {
+        return Err(line
+            .error_at(outputs, "Expected four output digits.")
+            .into());
+    }
+    let outputs = outputs
+        .split_whitespace()
+        .map(sort_string)
+        .collect::<Vec<String>>();
+
+    Ok(Entry {
+        patterns: patterns.split_whitespace().map(str::to_string).collect(),
+        outputs,
+    })
+}
+
+fn decode_entry(entry: &Entry) -> Result<usize> {
+    let mapping = extract_digit_map(&entry.patterns)?;
+
+    let mut result = 0_usize;
+
+    for digit in entry.outputs.iter() {
+        let (i, _) = mapping
+            .iter()
+            .enumerate()
+            .find(|&(_, s)| s == digit)
+            .ok_or_else(|| Error::unsolvable("Unable to translate digit."))?;
+        result *= 10;
+        result += i;
+    }
+
+    Ok(result)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Notes;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        Ok(input
+            .entries()
+            .iter()
+            .map(|e| {
+                e.outputs
+                    .iter()
+                    .filter(|s| s.len() == 2 || s.len() == 3 || s.len() == 4 || s.len() == 7)
+                    .count()
+            })
+            .sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let ints = input
+            .entries()
+            .iter()
+            .map(Entry::decode)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ints.iter().sum())
+    }
+}
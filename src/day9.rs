@@ -0,0 +1,116 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::traverse;
+use crate::viz::{self, Frame};
+use crate::Solution;
+
+/// The height of each location on the cave floor, one digit per location.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HeatMap {
+    heights: Grid<u8>,
+}
+
+impl HeatMap {
+    pub fn new(heights: Grid<u8>) -> Self {
+        Self { heights }
+    }
+
+    pub fn heights(&self) -> &Grid<u8> {
+        &self.heights
+    }
+
+    fn find_minima(&self) -> Vec<((usize, usize), u8)> {
+        self.heights
+            .positions()
+            .map(|p| (p, self.heights[p]))
+            .filter(|&((r, c), value)| {
+                self.heights
+                    .neighbours4(r, c)
+                    .all(|n| self.heights[n] > value)
+            })
+            .collect()
+    }
+
+    fn find_basin_sizes(&self) -> Vec<usize> {
+        let minima = self.find_minima();
+        let (width, height) = (self.heights.width(), self.heights.height());
+        let mut filled = viz::recording().then(|| Grid::new(width, height, false));
+
+        let mut basin_sizes = minima
+            .iter()
+            .map(|&((r, c), _)| {
+                let basin = self.find_basin(r, c);
+                if let Some(filled) = filled.as_mut() {
+                    for &p in basin.iter() {
+                        filled[p] = true;
+                    }
+                    viz::frame(|| self.frame(filled));
+                }
+                basin.len()
+            })
+            .collect::<Vec<usize>>();
+        basin_sizes.sort();
+        basin_sizes
+    }
+
+    fn find_basin(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        traverse::flood_fill(
+            (row, col),
+            |&(r, c)| self.heights.neighbours4(r, c),
+            |&p| self.heights[p] != 9,
+        )
+    }
+
+    /// Ridges of height 9 are black, and basins brighten once filled.
+    fn frame(&self, filled: &Grid<bool>) -> Frame {
+        Frame::new(Grid::from_fn(
+            self.heights.width(),
+            self.heights.height(),
+            |row, col| match self.heights[(row, col)] {
+                9 => 0,
+                h if filled[(row, col)] => 160 + (8 - h) * 11,
+                h => 40 + (8 - h) * 8,
+            },
+        ))
+    }
+}
+
+impl FromStr for HeatMap {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Self::new(Grid::parse_digits(s)?))
+    }
+}
+
+impl fmt::Display for HeatMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.heights)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = HeatMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        let minima = input.find_minima();
+        Ok(minima.iter().map(|&((_, _), v)| v as usize + 1).sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        let basin_sizes = input.find_basin_sizes();
+        Ok(basin_sizes.iter().rev().take(3).product())
+    }
+}
@@ -0,0 +1,114 @@
+use std::error;
+use std::fmt;
+
+use crate::error::ParseError;
+
+#[derive(Debug)]
+pub struct Diagnostic {
+    path: String,
+    line_text: Option<String>,
+    error: ParseError,
+}
+
+impl Diagnostic {
+    pub fn new(error: ParseError, input: &str, path: impl Into<String>) -> Self {
+        let line_text = input
+            .lines()
+            .nth(error.line().saturating_sub(1))
+            .map(|l| l.replace('\t', " "));
+
+        Self {
+            path: path.into(),
+            line_text,
+            error,
+        }
+    }
+
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = self.error.line();
+        let column = self.error.column();
+        let gutter = " ".repeat(line.to_string().len());
+
+        writeln!(f, "error: {}", self.error.message())?;
+        write!(f, "{}--> {}:{}:{}", gutter, self.path, line, column)?;
+
+        if let Some(text) = &self.line_text {
+            let width = text.chars().count();
+            let length = self
+                .error
+                .length()
+                .min((width + 1).saturating_sub(column))
+                .max(1);
+
+            writeln!(f)?;
+            writeln!(f, "{} |", gutter)?;
+            writeln!(f, "{} | {}", line, text)?;
+            write!(
+                f,
+                "{} | {}{}",
+                gutter,
+                " ".repeat(column.saturating_sub(1)),
+                "^".repeat(length)
+            )?;
+            if let Some(expected) = self.error.expected() {
+                write!(f, " expected {}", expected)?;
+            }
+        } else if let Some(expected) = self.error.expected() {
+            write!(f, "\n{} = expected {}", gutter, expected)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for Diagnostic {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        error::Error::source(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::InputLine;
+
+    #[test]
+    fn test_render() {
+        let input = "on x=1..2,y=3..4,z=5..6\noff x=1..2,y=oops,z=5..6\n";
+        let line = InputLine::new(2, input.lines().nth(1).unwrap());
+        let part = &line.text()[13..17];
+        let error = line
+            .error_at(part, "Unable to parse range.")
+            .with_expected("a range like '-12..34'");
+
+        let expected = "\
+error: Unable to parse range.
+ --> day22.txt:2:14
+  |
+2 | off x=1..2,y=oops,z=5..6
+  |              ^^^^ expected a range like '-12..34'";
+        assert_eq!(
+            expected,
+            Diagnostic::new(error, input, "day22.txt").to_string()
+        );
+    }
+
+    #[test]
+    fn test_render_without_line() {
+        let error = ParseError::new(1, 1, "Input file is empty.").with_expected("a scanner");
+        let expected = "\
+error: Input file is empty.
+ --> empty.txt:1:1
+  = expected a scanner";
+        assert_eq!(
+            expected,
+            Diagnostic::new(error, "", "empty.txt").to_string()
+        );
+    }
+}
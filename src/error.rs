@@ -1,13 +1,199 @@
-use std::fmt;
 use std::error;
+use std::fmt;
+use std::io;
+use std::result;
+
+pub type Result<T> = result::Result<T, Error>;
 
 #[derive(Debug)]
-pub struct Error(pub String);
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+    InvalidState(String),
+    Unsolvable(String),
+    InvalidConfig(String),
+}
+
+impl Error {
+    pub fn invalid_state(message: impl Into<String>) -> Self {
+        Self::InvalidState(message.into())
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Self::Unsolvable(message.into())
+    }
+
+    pub fn invalid_config(message: impl Into<String>) -> Self {
+        Self::InvalidConfig(message.into())
+    }
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self)
+        match self {
+            // The details of these are the error's source.
+            Error::Io(_) => f.write_str("I/O error"),
+            Error::Parse(_) => f.write_str("Parse error"),
+            Error::InvalidState(message) => write!(f, "Invalid puzzle state: {}", message),
+            Error::Unsolvable(message) => write!(f, "Unsolvable input: {}", message),
+            Error::InvalidConfig(message) => write!(f, "Invalid configuration: {}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse(e) => Some(e),
+            Error::InvalidState(_) | Error::Unsolvable(_) | Error::InvalidConfig(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// `error` followed by each of its sources in turn, one per line.
+pub fn describe(error: &dyn error::Error) -> String {
+    let mut description = error.to_string();
+    let mut source = error.source();
+    while let Some(e) = source {
+        description.push_str(&format!("\n  caused by: {}", e));
+        source = e.source();
+    }
+    description
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    length: usize,
+    message: String,
+    expected: Option<String>,
+    source: Option<Box<dyn error::Error + Send + Sync>>,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            length: 1,
+            message: message.into(),
+            expected: None,
+            source: None,
+        }
+    }
+
+    pub fn with_length(mut self, length: usize) -> Self {
+        self.length = length.max(1);
+        self
+    }
+
+    pub fn with_expected(mut self, expected: impl Into<String>) -> Self {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    pub fn with_source(mut self, source: impl error::Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn expected(&self) -> Option<&str> {
+        self.expected.as_deref()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|e| e as &(dyn error::Error + 'static))
     }
 }
 
-impl error::Error for Error {}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_display() {
+        let error = Error::from(ParseError::new(3, 7, "Unexpected character 'x'."));
+        assert_eq!("Parse error", error.to_string());
+
+        let error = Error::unsolvable("No board wins.");
+        assert_eq!("Unsolvable input: No board wins.", error.to_string());
+    }
+
+    #[test]
+    fn test_source() {
+        let int_error = "x".parse::<i32>().unwrap_err();
+        let error =
+            Error::from(ParseError::new(1, 1, "Bad integer.").with_source(int_error.clone()));
+        let source = error.source().expect("Missing error source.");
+        assert_eq!("line 1, column 1: Bad integer.", source.to_string());
+        let cause = source.source().expect("Missing parse error cause.");
+        assert_eq!(int_error.to_string(), cause.to_string());
+
+        let error = Error::from(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn test_describe() {
+        let int_error = "x".parse::<i32>().unwrap_err();
+        let error =
+            Error::from(ParseError::new(1, 1, "Bad integer.").with_source(int_error.clone()));
+        let description = describe(&error);
+        assert_eq!(
+            format!(
+                "Parse error\n  caused by: line 1, column 1: Bad integer.\n  caused by: {}",
+                int_error
+            ),
+            description
+        );
+        assert_eq!(1, description.matches("Bad integer.").count());
+
+        let error = Error::from(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        assert_eq!("I/O error\n  caused by: missing", describe(&error));
+    }
+}
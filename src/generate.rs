@@ -0,0 +1,917 @@
+//! Random, valid puzzle inputs for scale testing and fuzzing the parsers.
+//!
+//! Each day's generator takes a size, whose meaning depends on the puzzle
+//! (lines, grid side, scanners, ...), and draws everything else from a seeded
+//! [`Rng`], so the same seed and size always produce the same input.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::geometry::{self, Matrix, Vector};
+use crate::grid::Grid;
+
+/// SplitMix64, which is small, fast and plenty random enough for test inputs.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `0..bound`, which must be non-zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0);
+        self.next_u64() % bound
+    }
+
+    /// A value in `low..=high`.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low <= high);
+        low + self.below((high - low) as u64 + 1) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64 <= probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+    /// The size that produces an input roughly like the real puzzle's.
+    pub default_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+pub fn generator(day: usize) -> Option<Generator> {
+    let (default_size, generate): (usize, fn(&mut Rng, usize) -> String) = match day {
+        1 => (2000, day1),
+        2 => (1000, day2),
+        3 => (1000, day3),
+        4 => (100, day4),
+        5 => (500, day5),
+        6 => (300, day6),
+        7 => (1000, day7),
+        8 => (200, day8),
+        9 => (100, day9),
+        10 => (100, day10),
+        11 => (10, day11),
+        12 => (6, day12),
+        13 => (900, day13),
+        14 => (20, day14),
+        15 => (100, day15),
+        16 => (60, day16),
+        17 => (150, day17),
+        18 => (100, day18),
+        19 => (30, day19),
+        20 => (100, day20),
+        21 => (1, day21),
+        22 => (420, day22),
+        23 => (1, day23),
+        24 => (1, day24),
+        25 => (137, day25),
+        _ => return None,
+    };
+    Some(Generator {
+        default_size,
+        generate,
+    })
+}
+
+/// Joins one generated line per item, each followed by a newline.
+fn lines(count: usize, mut line: impl FnMut(usize) -> String) -> String {
+    let mut output = String::new();
+    for i in 0..count {
+        output.push_str(&line(i));
+        output.push('\n');
+    }
+    output
+}
+
+fn comma_separated(values: impl IntoIterator<Item = i64>) -> String {
+    values
+        .into_iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn grid(
+    rng: &mut Rng,
+    width: usize,
+    height: usize,
+    mut cell: impl FnMut(&mut Rng) -> char,
+) -> String {
+    lines(height, |_| (0..width).map(|_| cell(rng)).collect())
+}
+
+fn day1(rng: &mut Rng, size: usize) -> String {
+    let mut depth = rng.range(100, 200);
+    lines(size, |_| {
+        depth = (depth + rng.range(-10, 20)).max(0);
+        depth.to_string()
+    })
+}
+
+fn day2(rng: &mut Rng, size: usize) -> String {
+    let mut depth = 0;
+    lines(size, |_| {
+        let amount = rng.range(1, 9);
+        match rng.below(3) {
+            0 => format!("forward {}", amount),
+            1 if depth >= amount => {
+                depth -= amount;
+                format!("up {}", amount)
+            }
+            _ => {
+                depth += amount;
+                format!("down {}", amount)
+            }
+        }
+    })
+}
+
+/// Fills `values` with `count` distinct `width` bit numbers below `prefix`
+/// such that any group sharing a prefix differs in the next bit.
+fn diagnostic_numbers(rng: &mut Rng, prefix: u64, width: u32, count: u64, values: &mut Vec<u64>) {
+    if count == 1 {
+        values.push(prefix << width | rng.below(1 << width));
+        return;
+    }
+    let half = 1 << (width - 1);
+    let low = count.saturating_sub(half).max(1);
+    let high = half.min(count - 1);
+    let ones = rng.range(low as i64, high as i64) as u64;
+    diagnostic_numbers(rng, prefix << 1, width - 1, count - ones, values);
+    diagnostic_numbers(rng, prefix << 1 | 1, width - 1, ones, values);
+}
+
+/// The oxygen and CO2 filters both need every group of two or more remaining
+/// numbers to disagree on the next bit, or the least common value is missing.
+fn day3(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 1 << 16) as u64;
+    let width = 12.max(u64::BITS - size.leading_zeros() + 1).min(16);
+    let mut values = Vec::new();
+    diagnostic_numbers(rng, 0, width, size, &mut values);
+    rng.shuffle(&mut values);
+    lines(values.len(), |i| {
+        format!("{:0width$b}", values[i], width = width as usize)
+    })
+}
+
+/// Every number on the boards is drawn, so every board wins eventually.
+fn day4(rng: &mut Rng, size: usize) -> String {
+    let mut numbers = (0..100).collect::<Vec<_>>();
+    rng.shuffle(&mut numbers);
+    let mut output = comma_separated(numbers.iter().copied());
+    output.push('\n');
+
+    for _ in 0..size.max(1) {
+        let mut board = numbers.clone();
+        rng.shuffle(&mut board);
+        output.push('\n');
+        for row in board[..25].chunks(5) {
+            let row = row.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>();
+            output.push_str(&row.join(" "));
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// Horizontal, vertical and 45 degree lines.
+fn day5(rng: &mut Rng, size: usize) -> String {
+    lines(size, |_| {
+        let x1 = rng.range(10, 990);
+        let y1 = rng.range(10, 990);
+        let length = rng.range(1, x1.min(y1).min(990 - x1).min(990 - y1).max(1));
+        let (dx, dy) = *rng.choose(&[(1, 0), (0, 1), (1, 1), (1, -1), (-1, 0), (0, -1)]);
+        let (x2, y2) = (x1 + dx * length, y1 + dy * length);
+        format!("{},{} -> {},{}", x1, y1, x2, y2)
+    })
+}
+
+fn day6(rng: &mut Rng, size: usize) -> String {
+    comma_separated((0..size.max(1)).map(|_| rng.range(1, 5)))
+}
+
+fn day7(rng: &mut Rng, size: usize) -> String {
+    comma_separated((0..size.max(1)).map(|_| rng.range(0, 1900)))
+}
+
+/// Segments of each digit on an unscrambled display.
+const SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+fn day8(rng: &mut Rng, size: usize) -> String {
+    lines(size, |_| {
+        let mut wiring = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+        rng.shuffle(&mut wiring);
+        let scramble = |rng: &mut Rng, digit: usize| {
+            let mut pattern = SEGMENTS[digit]
+                .bytes()
+                .map(|s| wiring[(s - b'a') as usize])
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut pattern);
+            pattern.into_iter().collect::<String>()
+        };
+
+        let mut digits = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut digits);
+        let patterns = digits.iter().map(|&d| scramble(rng, d)).collect::<Vec<_>>();
+        let outputs = (0..4)
+            .map(|_| {
+                let digit = rng.index(10);
+                scramble(rng, digit)
+            })
+            .collect::<Vec<_>>();
+        format!("{} | {}", patterns.join(" "), outputs.join(" "))
+    })
+}
+
+/// Basins grow outwards from random low points and are walled off from each
+/// other by 9s, like the real height maps.
+fn day9(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let low_points = (0..(size * size / 40).max(1))
+        .map(|_| (rng.index(size) as i64, rng.index(size) as i64))
+        .collect::<Vec<_>>();
+    let nearest = Grid::from_fn(size, size, |row, col| {
+        (0..low_points.len())
+            .map(|i| {
+                let (r, c) = low_points[i];
+                ((r - row as i64).abs() + (c - col as i64).abs(), i)
+            })
+            .min()
+            .unwrap()
+    });
+
+    let heights = Grid::from_fn(size, size, |row, col| {
+        let (distance, basin) = nearest[(row, col)];
+        let border = [(row + 1, col), (row, col + 1)]
+            .iter()
+            .any(|&p| p.0 < size && p.1 < size && nearest[p].1 != basin);
+        if border {
+            9
+        } else {
+            distance.min(8) as u32
+        }
+    });
+    heights.render(|&h| char::from_digit(h, 10).unwrap())
+}
+
+/// Lines that are either corrupted by a mismatched closing bracket or left
+/// incomplete.
+fn day10(rng: &mut Rng, size: usize) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    // Each bracket left open multiplies the completion score by 5, so deeper
+    // nesting would overflow it.
+    const MAX_DEPTH: usize = 20;
+
+    lines(size, |_| {
+        let length = rng.range(80, 110) as usize;
+        let corrupt_at = rng
+            .chance(0.5)
+            .then(|| rng.range(10, length as i64 - 1) as usize);
+        let mut line = String::new();
+        let mut stack = Vec::new();
+
+        while line.len() < length || stack.is_empty() {
+            if Some(line.len()) == corrupt_at && !stack.is_empty() {
+                let expected = stack.pop().unwrap();
+                let wrong = PAIRS.iter().map(|p| p.1).filter(|&c| c != expected);
+                line.push(*rng.choose(&wrong.collect::<Vec<_>>()));
+            } else if stack.is_empty() || (stack.len() < MAX_DEPTH && rng.chance(0.55)) {
+                let (open, close) = *rng.choose(&PAIRS);
+                line.push(open);
+                stack.push(close);
+            } else {
+                line.push(stack.pop().unwrap());
+            }
+        }
+        line
+    })
+}
+
+fn octopuses_synchronise(energies: &Grid<u32>, max_steps: usize) -> bool {
+    let mut energies = energies.clone();
+    for _ in 0..max_steps {
+        energies.iter_mut().for_each(|e| *e += 1);
+        let mut flashing = energies
+            .positions()
+            .filter(|&p| energies[p] > 9)
+            .collect::<Vec<_>>();
+        let mut flashes = 0;
+        while let Some((row, col)) = flashing.pop() {
+            flashes += 1;
+            for p in energies.neighbours8(row, col).collect::<Vec<_>>() {
+                energies[p] += 1;
+                if energies[p] == 10 {
+                    flashing.push(p);
+                }
+            }
+        }
+        if flashes == energies.len() {
+            return true;
+        }
+        energies.iter_mut().filter(|e| **e > 9).for_each(|e| *e = 0);
+    }
+    false
+}
+
+/// Random grids don't always end up flashing in sync, so keep drawing until
+/// one does within a thousand steps.
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    loop {
+        let energies = Grid::from_fn(size, size, |_, _| rng.below(10) as u32);
+        if octopuses_synchronise(&energies, 1000) {
+            return energies.render(|&e| char::from_digit(e, 10).unwrap());
+        }
+    }
+}
+
+/// `size` small caves and a couple of big ones, which are never linked to
+/// each other so the number of paths stays finite.
+fn day12(rng: &mut Rng, size: usize) -> String {
+    let mut caves = vec!["start".to_string(), "end".to_string()];
+    let small = |i: usize| {
+        format!(
+            "{}{}",
+            (b'a' + (i / 26) as u8) as char,
+            (b'a' + (i % 26) as u8) as char
+        )
+    };
+    caves.extend((0..size.max(1)).map(small));
+    let big = ["HN", "LN", "XQ"][..(size / 3).clamp(1, 3)]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>();
+
+    let mut edges = HashSet::new();
+    // A chain through every small cave keeps the graph connected.
+    for pair in caves[2..].windows(2) {
+        edges.insert((pair[0].clone(), pair[1].clone()));
+    }
+    edges.insert(("start".to_string(), caves[2].clone()));
+    edges.insert((caves[caves.len() - 1].clone(), "end".to_string()));
+    for cave in big.iter() {
+        for _ in 0..3 {
+            let other = rng.choose(&caves).clone();
+            edges.insert((cave.clone(), other));
+        }
+    }
+    for _ in 0..size / 2 {
+        let first = rng.choose(&caves[2..]).clone();
+        let second = rng.choose(&caves).clone();
+        if first != second && !edges.contains(&(second.clone(), first.clone())) {
+            edges.insert((first, second));
+        }
+    }
+
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort();
+    rng.shuffle(&mut edges);
+    lines(edges.len(), |i| format!("{}-{}", edges[i].0, edges[i].1))
+}
+
+/// Each fold halves the paper exactly, so no dot ever folds past the edge.
+fn day13(rng: &mut Rng, size: usize) -> String {
+    let (mut width, mut height) = (40_usize, 6_usize);
+    let mut axes = vec!['x'; 5];
+    axes.extend(['y'; 7]);
+    rng.shuffle(&mut axes);
+
+    let mut folds = Vec::new();
+    for &axis in axes.iter() {
+        let extent = if axis == 'x' { &mut width } else { &mut height };
+        folds.push((axis, *extent));
+        *extent = 2 * *extent + 1;
+    }
+    folds.reverse();
+
+    // A dot on a fold line stays put and ends up past the edge of the next
+    // fold along that axis.
+    let on_fold_line = |mut dot: (usize, usize)| {
+        folds.iter().any(|&(axis, line)| {
+            let coordinate = if axis == 'x' { &mut dot.0 } else { &mut dot.1 };
+            if *coordinate > line {
+                *coordinate = 2 * line - *coordinate;
+            }
+            *coordinate == line
+        })
+    };
+
+    let mut dots = HashSet::new();
+    while dots.len() < size.min(width * height / 2) {
+        let dot = (rng.index(width), rng.index(height));
+        if !on_fold_line(dot) {
+            dots.insert(dot);
+        }
+    }
+    let mut output = String::new();
+    let mut dots = dots.into_iter().collect::<Vec<_>>();
+    dots.sort();
+    rng.shuffle(&mut dots);
+    for (x, y) in dots {
+        let _ = writeln!(output, "{},{}", x, y);
+    }
+    output.push('\n');
+    output.push_str(&lines(folds.len(), |i| {
+        format!("fold along {}={}", folds[i].0, folds[i].1)
+    }));
+    output
+}
+
+/// Rules cover every pair of elements, so no pair is ever left unmatched.
+fn day14(rng: &mut Rng, size: usize) -> String {
+    const ELEMENTS: [char; 10] = ['B', 'C', 'F', 'H', 'K', 'N', 'O', 'P', 'S', 'V'];
+    let template = (0..size.max(2))
+        .map(|_| *rng.choose(&ELEMENTS))
+        .collect::<String>();
+
+    let mut rules = Vec::new();
+    for &first in ELEMENTS.iter() {
+        for &second in ELEMENTS.iter() {
+            rules.push(format!("{}{} -> {}", first, second, rng.choose(&ELEMENTS)));
+        }
+    }
+    rng.shuffle(&mut rules);
+    format!(
+        "{}\n\n{}",
+        template,
+        lines(rules.len(), |i| rules[i].clone())
+    )
+}
+
+fn day15(rng: &mut Rng, size: usize) -> String {
+    grid(rng, size, size, |rng| {
+        char::from_digit(rng.range(1, 9) as u32, 10).unwrap()
+    })
+}
+
+struct Bits(Vec<bool>);
+
+impl Bits {
+    fn push(&mut self, value: u64, width: u32) {
+        for i in (0..width).rev() {
+            self.0.push(value >> i & 1 == 1);
+        }
+    }
+}
+
+/// Encodes a random packet holding at most `budget` packets and returns its
+/// value, keeping every value that can be reached well inside 64 bits.
+fn bits_packet(rng: &mut Rng, bits: &mut Bits, budget: usize, depth: usize) -> u64 {
+    bits.push(rng.below(8), 3);
+
+    if budget <= 1 || depth >= 6 || rng.chance(0.3) {
+        let bits_wide = rng.range(1, 24);
+        let value = rng.below(1 << bits_wide);
+        bits.push(4, 3);
+        let groups = (64 - value.leading_zeros()).div_ceil(4).max(1);
+        for group in (0..groups).rev() {
+            bits.push((group > 0) as u64, 1);
+            bits.push(value >> (4 * group) & 0xf, 4);
+        }
+        return value;
+    }
+
+    let mut packet_type = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let count = if packet_type >= 5 {
+        2
+    } else {
+        rng.range(1, 4.min(budget as i64 - 1).max(1)) as usize
+    };
+
+    let mut body = Bits(Vec::new());
+    let share = ((budget - 1) / count).max(1);
+    let values = (0..count)
+        .map(|_| bits_packet(rng, &mut body, share, depth + 1))
+        .collect::<Vec<_>>();
+
+    let product = values
+        .iter()
+        .try_fold(1_u64, |acc, &v| acc.checked_mul(v))
+        .filter(|&p| p < 1 << 48);
+    if packet_type == 1 && product.is_none() {
+        packet_type = 3;
+    }
+    bits.push(packet_type, 3);
+
+    if rng.chance(0.5) {
+        bits.push(0, 1);
+        bits.push(body.0.len() as u64, 15);
+    } else {
+        bits.push(1, 1);
+        bits.push(count as u64, 11);
+    }
+    bits.0.extend(body.0);
+
+    match packet_type {
+        0 => values.iter().sum(),
+        1 => product.unwrap(),
+        2 => *values.iter().min().unwrap(),
+        3 => *values.iter().max().unwrap(),
+        5 => (values[0] > values[1]) as u64,
+        6 => (values[0] < values[1]) as u64,
+        _ => (values[0] == values[1]) as u64,
+    }
+}
+
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let mut bits = Bits(Vec::new());
+    bits_packet(rng, &mut bits, size.max(1), 0);
+    while !bits.0.len().is_multiple_of(8) {
+        bits.0.push(false);
+    }
+    bits.0
+        .chunks(4)
+        .map(|nibble| {
+            let value = nibble.iter().fold(0, |acc, &b| acc << 1 | b as u32);
+            char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect()
+}
+
+fn day17(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(10) as i64;
+    let x_min = rng.range(size / 2, size);
+    let y_min = -rng.range(size / 2, size);
+    format!(
+        "target area: x={}..{}, y={}..{}",
+        x_min,
+        x_min + rng.range(size / 10, size / 4),
+        y_min,
+        y_min + rng.range(size / 10, size / 4)
+    )
+}
+
+/// A reduced snailfish number, nested at most four pairs deep.
+fn snailfish(rng: &mut Rng, depth: usize) -> String {
+    if depth > 0 && (depth == 4 || rng.chance(0.3)) {
+        return rng.below(10).to_string();
+    }
+    format!(
+        "[{},{}]",
+        snailfish(rng, depth + 1),
+        snailfish(rng, depth + 1)
+    )
+}
+
+fn day18(rng: &mut Rng, size: usize) -> String {
+    lines(size.max(1), |_| snailfish(rng, 0))
+}
+
+/// A day 19 input along with where its scanners really are.
+#[derive(Debug, Clone)]
+pub struct ScannerReports {
+    pub input: String,
+    /// Each scanner's position relative to scanner 0.
+    pub positions: Vec<Vector<3>>,
+    pub beacons: usize,
+}
+
+impl ScannerReports {
+    pub fn max_distance(&self) -> i32 {
+        let positions = self.positions.iter();
+        positions
+            .clone()
+            .flat_map(|&a| positions.clone().map(move |&b| (a - b).manhattan()))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+const SCANNER_RANGE: i32 = 1000;
+
+fn random_point(rng: &mut Rng, lower: Vector<3>, upper: Vector<3>) -> Vector<3> {
+    Vector(std::array::from_fn(|i| {
+        rng.range(lower[i] as i64, upper[i] as i64) as i32
+    }))
+}
+
+fn in_range(scanner: Vector<3>, beacon: Vector<3>) -> bool {
+    (beacon - scanner).chebyshev() <= SCANNER_RANGE
+}
+
+/// Places `count` scanners in a chain, each sharing at least twelve beacons
+/// with one placed shortly before it, with beacons otherwise spread as thinly
+/// as in the real reports. Every beacon in range of a scanner is reported in
+/// that scanner's own random orientation; scanner 0 keeps the reference one.
+pub fn scanner_reports(rng: &mut Rng, count: usize) -> ScannerReports {
+    const BEACONS_PER_SCANNER: usize = 26;
+    let rotations = geometry::rotations::<3>();
+    let reach = Vector([SCANNER_RANGE; 3]);
+    let mut positions: Vec<Vector<3>> = Vec::new();
+    let mut beacons = HashSet::new();
+
+    for i in 0..count.max(1) {
+        let position = if i == 0 {
+            Vector::zero()
+        } else {
+            let parent = positions[i - 1 - rng.index(i.min(3))];
+            let step = Vector([(); 3].map(|_| {
+                let sign = if rng.chance(0.5) { -1 } else { 1 };
+                sign * rng.range(300, 700) as i32
+            }));
+            let position = parent + step;
+
+            let lower = Vector(std::array::from_fn(|a| parent[a].max(position[a]))) - reach;
+            let upper = Vector(std::array::from_fn(|a| parent[a].min(position[a]))) + reach;
+            let mut shared = beacons
+                .iter()
+                .filter(|&&b| in_range(parent, b) && in_range(position, b))
+                .count();
+            while shared < 12 {
+                shared += beacons.insert(random_point(rng, lower, upper)) as usize;
+            }
+            position
+        };
+
+        // Top up only the space no earlier scanner covers, so overlaps don't
+        // grow denser than the rest.
+        let mut seen = beacons.iter().filter(|&&b| in_range(position, b)).count();
+        for _ in 0..1000 {
+            if seen >= BEACONS_PER_SCANNER {
+                break;
+            }
+            let beacon = random_point(rng, position - reach, position + reach);
+            if positions.iter().all(|&p| !in_range(p, beacon)) && beacons.insert(beacon) {
+                seen += 1;
+            }
+        }
+        positions.push(position);
+    }
+
+    let mut beacon_list = beacons.iter().copied().collect::<Vec<_>>();
+    beacon_list.sort();
+    let mut input = String::new();
+    for (i, &position) in positions.iter().enumerate() {
+        let rotation: Matrix<3> = if i == 0 {
+            Matrix::identity()
+        } else {
+            *rng.choose(&rotations)
+        };
+        let mut seen = beacon_list
+            .iter()
+            .filter(|&&b| in_range(position, b))
+            .map(|&b| rotation.transpose() * (b - position))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut seen);
+
+        if i > 0 {
+            input.push('\n');
+        }
+        let _ = writeln!(input, "--- scanner {} ---", i);
+        for point in seen {
+            let _ = writeln!(input, "{}", point);
+        }
+    }
+
+    ScannerReports {
+        input,
+        positions,
+        beacons: beacons.len(),
+    }
+}
+
+fn day19(rng: &mut Rng, size: usize) -> String {
+    scanner_reports(rng, size).input
+}
+
+/// If the algorithm lights every dark region it also darkens every lit one,
+/// so the infinite background flips back and forth rather than staying lit.
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let mut algorithm = (0..512)
+        .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+        .collect::<Vec<_>>();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+    let image = grid(rng, size.max(1), size.max(1), |rng| {
+        if rng.chance(0.5) {
+            '#'
+        } else {
+            '.'
+        }
+    });
+    format!("{}\n\n{}", algorithm.into_iter().collect::<String>(), image)
+}
+
+fn day21(rng: &mut Rng, _size: usize) -> String {
+    lines(2, |i| {
+        format!("Player {} starting position: {}", i + 1, rng.range(1, 10))
+    })
+}
+
+/// The first steps stay inside the initialization region, as in the puzzle.
+fn day22(rng: &mut Rng, size: usize) -> String {
+    lines(size.max(1), |i| {
+        let (extent, length) = if i < 20 { (50, 40) } else { (100_000, 40_000) };
+        let ranges = (0..3)
+            .map(|_| {
+                let low = rng.range(-extent, extent - length);
+                (low, low + rng.range(length / 4, length))
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "{} x={}..{},y={}..{},z={}..{}",
+            if i == 0 || rng.chance(0.6) {
+                "on"
+            } else {
+                "off"
+            },
+            ranges[0].0,
+            ranges[0].1,
+            ranges[1].0,
+            ranges[1].1,
+            ranges[2].0,
+            ranges[2].1
+        )
+    })
+}
+
+fn day23(rng: &mut Rng, _size: usize) -> String {
+    let mut amphipods = ['A', 'A', 'B', 'B', 'C', 'C', 'D', 'D'];
+    rng.shuffle(&mut amphipods);
+    let [a, b, c, d, e, f, g, h] = amphipods;
+    format!(
+        "#############\n#...........#\n###{}#{}#{}#{}###\n  #{}#{}#{}#{}#\n  #########\n",
+        a, b, c, d, e, f, g, h
+    )
+}
+
+/// A MONAD program reading `digits` digits, which must be even. Half the
+/// blocks push a digit onto the base-26 stack in `z` and half pop one, nested
+/// like brackets. Each pop compares its digit with the pushed one offset by at
+/// most eight, so some model number is always accepted.
+pub fn monad(rng: &mut Rng, digits: usize) -> String {
+    let mut pushes = vec![true; digits / 2];
+    pushes.extend(vec![false; digits / 2]);
+    // Shuffle until the sequence is a valid bracket nesting.
+    loop {
+        rng.shuffle(&mut pushes);
+        let mut depth = 0_i32;
+        if pushes.iter().all(|&p| {
+            depth += if p { 1 } else { -1 };
+            depth >= 0
+        }) {
+            break;
+        }
+    }
+
+    let mut stack = Vec::new();
+    let mut output = String::new();
+    for push in pushes {
+        let (divisor, check, modifier) = if push {
+            let modifier = rng.range(1, 16);
+            stack.push(modifier);
+            (1, rng.range(10, 16), modifier)
+        } else {
+            let pushed = stack.pop().unwrap();
+            let difference = rng.range(-8, 8);
+            (26, difference - pushed, rng.range(1, 16))
+        };
+        let _ = write!(
+            output,
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+             mul y x\nadd z y\n",
+            divisor, check, modifier
+        );
+    }
+    output
+}
+
+fn day24(rng: &mut Rng, _size: usize) -> String {
+    monad(rng, 14)
+}
+
+fn sea_cucumbers_stop(cells: &Grid<char>, max_steps: usize) -> bool {
+    let mut cells = cells.clone();
+    for _ in 0..max_steps {
+        let mut moved = false;
+        for (herd, (dr, dc)) in [('>', (0, 1)), ('v', (1, 0))] {
+            let moves = cells
+                .positions()
+                .filter(|&(r, c)| {
+                    cells[(r, c)] == herd
+                        && *cells.get_wrapping(r as isize + dr, c as isize + dc) == '.'
+                })
+                .collect::<Vec<_>>();
+            moved |= !moves.is_empty();
+            for (r, c) in moves {
+                cells[(r, c)] = '.';
+                *cells.get_wrapping_mut(r as isize + dr, c as isize + dc) = herd;
+            }
+        }
+        if !moved {
+            return true;
+        }
+    }
+    false
+}
+
+/// Small or sparse grids can leave a herd circling forever, so keep drawing
+/// until one comes to a stop.
+fn day25(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    loop {
+        let cells = Grid::from_fn(size, size + 2, |_, _| match rng.below(4) {
+            0 => '>',
+            1 => 'v',
+            _ => '.',
+        });
+        if sea_cucumbers_stop(&cells, 100 * size) {
+            return cells.render(|&c| c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Settings;
+
+    #[test]
+    fn test_rng() {
+        let mut first = Rng::new(7);
+        let mut second = Rng::new(7);
+        let values = (0..100).map(|_| first.range(-3, 3)).collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            (0..100).map(|_| second.range(-3, 3)).collect::<Vec<_>>()
+        );
+        assert!(values.iter().all(|v| (-3..=3).contains(v)));
+        assert_eq!(7, values.iter().collect::<HashSet<_>>().len());
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for day in 1..=crate::NUM_DAYS {
+            let generator = generator(day).unwrap();
+            let size = match day {
+                11 | 12 | 21 | 23 | 24 => generator.default_size,
+                19 => 5,
+                _ => generator.default_size.min(20),
+            };
+            let solve = match crate::solver(day) {
+                Some(solve) => solve,
+                None => continue,
+            };
+            for seed in 0..3 {
+                let input = (generator.generate)(&mut Rng::new(seed), size);
+                if let Err(e) = solve(&input, &Settings::default()) {
+                    panic!("Day {} seed {} failed: {}\n{}", day, seed, e, input);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "day10")]
+    #[test]
+    fn test_day10_completion_scores_fit() {
+        use crate::day10::Day10;
+        use crate::Solution;
+
+        for seed in 0..5 {
+            let input = day10(&mut Rng::new(seed), 100).parse().unwrap();
+            assert!(Day10.part2(&input).is_ok(), "seed {}", seed);
+        }
+    }
+
+    #[cfg(feature = "day19")]
+    #[test]
+    fn test_scanner_reports() {
+        let reports = scanner_reports(&mut Rng::new(19), 6);
+        let solve = crate::solver(19).unwrap();
+        let (beacons, distance) = solve(&reports.input, &Settings::default()).unwrap();
+        assert_eq!(reports.beacons.to_string(), beacons);
+        assert_eq!(reports.max_distance().to_string(), distance);
+    }
+}
@@ -0,0 +1,409 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::input::InputLine;
+
+/// An integer vector in `N` dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector<const N: usize>(pub [i32; N]);
+
+impl<const N: usize> Vector<N> {
+    pub fn new(components: [i32; N]) -> Self {
+        Self(components)
+    }
+
+    pub fn zero() -> Self {
+        Self([0; N])
+    }
+
+    /// The unit vector along `axis`.
+    pub fn unit(axis: usize) -> Self {
+        let mut unit = Self::zero();
+        unit[axis] = 1;
+        unit
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &i32> {
+        self.0.iter()
+    }
+
+    pub fn manhattan(&self) -> i32 {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    pub fn chebyshev(&self) -> i32 {
+        self.0.iter().map(|c| c.abs()).max().unwrap_or_default()
+    }
+
+    pub fn signum(&self) -> Self {
+        Self(self.0.map(i32::signum))
+    }
+}
+
+impl<const N: usize> Default for Vector<N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const N: usize> From<[i32; N]> for Vector<N> {
+    fn from(components: [i32; N]) -> Self {
+        Self(components)
+    }
+}
+
+impl<const N: usize> Index<usize> for Vector<N> {
+    type Output = i32;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Vector<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> Add for Vector<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|i| self[i] + rhs[i]))
+    }
+}
+
+impl<const N: usize> Sub for Vector<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|i| self[i] - rhs[i]))
+    }
+}
+
+impl<const N: usize> AddAssign for Vector<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> SubAssign for Vector<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> Neg for Vector<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<i32> for Vector<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self(self.0.map(|c| c * rhs))
+    }
+}
+
+impl<const N: usize> fmt::Display for Vector<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> FromStr for Vector<N> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_vector(InputLine::new(1, s))
+    }
+}
+
+/// Parses comma separated components, such as `1,-2,3`.
+pub fn parse_vector<const N: usize>(line: InputLine) -> Result<Vector<N>> {
+    let mut point = Vector::zero();
+    let mut count = 0;
+
+    for (i, s) in line.text().split(',').enumerate() {
+        if i >= N {
+            return Err(line
+                .error_at(s, "Extra input found when parsing point.")
+                .into());
+        }
+        point[i] = s
+            .parse::<i32>()
+            .map_err(|e| line.error_at(s, "Unable to parse integer.").with_source(e))?;
+        count += 1;
+    }
+
+    if count < N {
+        let message = format!("Expected {} coordinates.", N);
+        return Err(line.error_at_end(message).into());
+    }
+
+    Ok(point)
+}
+
+/// A square integer matrix, indexed by `[row, col]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Matrix<const N: usize>(pub [[i32; N]; N]);
+
+impl<const N: usize> Matrix<N> {
+    pub fn zero() -> Self {
+        Self([[0; N]; N])
+    }
+
+    pub fn identity() -> Self {
+        let mut identity = Self::zero();
+        for i in 0..N {
+            identity[[i, i]] = 1;
+        }
+        identity
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self[[j, i]])
+        }))
+    }
+}
+
+impl<const N: usize> Default for Matrix<N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const N: usize> Index<[usize; 2]> for Matrix<N> {
+    type Output = i32;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        &self.0[index[0]][index[1]]
+    }
+}
+
+impl<const N: usize> IndexMut<[usize; 2]> for Matrix<N> {
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        &mut self.0[index[0]][index[1]]
+    }
+}
+
+impl<const N: usize> Mul for Matrix<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|i| {
+            std::array::from_fn(|k| (0..N).map(|j| self[[i, j]] * rhs[[j, k]]).sum())
+        }))
+    }
+}
+
+impl<const N: usize> Mul<Vector<N>> for Matrix<N> {
+    type Output = Vector<N>;
+
+    fn mul(self, rhs: Vector<N>) -> Self::Output {
+        Vector(std::array::from_fn(|i| {
+            (0..N).map(|j| self[[i, j]] * rhs[j]).sum()
+        }))
+    }
+}
+
+fn permutations(n: usize) -> Vec<(Vec<usize>, bool)> {
+    if n == 0 {
+        return vec![(Vec::new(), true)];
+    }
+
+    // Inserting `n - 1` at position `i` of a permutation of the smaller set
+    // adds `n - 1 - i` inversions.
+    let mut result = Vec::new();
+    for (perm, even) in permutations(n - 1) {
+        for i in 0..n {
+            let mut extended = perm.clone();
+            extended.insert(i, n - 1);
+            result.push((extended, even == (n - 1 - i).is_multiple_of(2)));
+        }
+    }
+    result
+}
+
+/// Every rotation of `N`-dimensional space that maps the axes onto axes,
+/// i.e. the signed permutation matrices with determinant 1. There are 24 in
+/// three dimensions.
+pub fn rotations<const N: usize>() -> Vec<Matrix<N>> {
+    let mut rotations = Vec::new();
+
+    for (perm, even) in permutations(N) {
+        for signs in 0..1_u32 << N {
+            let positive = signs.count_ones().is_multiple_of(2) == even;
+            if !positive {
+                continue;
+            }
+
+            let mut matrix = Matrix::zero();
+            for (row, &col) in perm.iter().enumerate() {
+                matrix[[row, col]] = if signs & (1 << row) != 0 { -1 } else { 1 };
+            }
+            rotations.push(matrix);
+        }
+    }
+
+    rotations
+}
+
+/// An axis-aligned box covering `lower` inclusive to `upper` exclusive on
+/// every axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Aabb<const N: usize> {
+    pub lower: Vector<N>,
+    pub upper: Vector<N>,
+}
+
+impl<const N: usize> Aabb<N> {
+    pub fn new(lower: Vector<N>, upper: Vector<N>) -> Self {
+        Self { lower, upper }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|i| self.upper[i] <= self.lower[i])
+    }
+
+    pub fn volume(&self) -> u64 {
+        if self.is_empty() {
+            return 0;
+        }
+        (0..N)
+            .map(|i| (self.upper[i] - self.lower[i]) as u64)
+            .product()
+    }
+
+    pub fn contains_point(&self, point: Vector<N>) -> bool {
+        (0..N).all(|i| point[i] >= self.lower[i] && point[i] < self.upper[i])
+    }
+
+    pub fn contains(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.lower[i] <= other.lower[i] && other.upper[i] <= self.upper[i])
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.lower[i] < other.upper[i] && other.lower[i] < self.upper[i])
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Self::new(
+            Vector(std::array::from_fn(|i| self.lower[i].max(other.lower[i]))),
+            Vector(std::array::from_fn(|i| self.upper[i].min(other.upper[i]))),
+        ))
+    }
+
+    /// Splits the part of `self` outside `other` into at most `3^N - 1`
+    /// disjoint boxes.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![*self];
+        };
+
+        let spans: [[i32; 4]; N] = std::array::from_fn(|i| {
+            [
+                self.lower[i],
+                overlap.lower[i],
+                overlap.upper[i],
+                self.upper[i],
+            ]
+        });
+
+        let mut boxes = Vec::new();
+        for index in 0..3_usize.pow(N as u32) {
+            let mut lower = Vector::zero();
+            let mut upper = Vector::zero();
+            let mut digits = index;
+            for i in 0..N {
+                let slab = digits % 3;
+                digits /= 3;
+                lower[i] = spans[i][slab];
+                upper[i] = spans[i][slab + 1];
+            }
+
+            let piece = Self::new(lower, upper);
+            if !piece.is_empty() && !overlap.contains(&piece) {
+                boxes.push(piece);
+            }
+        }
+        boxes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector() {
+        let a = Vector::new([1, -2, 3]);
+        let b = Vector::from([4, 5, -6]);
+        assert_eq!(Vector::new([5, 3, -3]), a + b);
+        assert_eq!(Vector::new([-3, -7, 9]), a - b);
+        assert_eq!(Vector::new([2, -4, 6]), a * 2);
+        assert_eq!(6, a.manhattan());
+        assert_eq!(3, a.chebyshev());
+        assert_eq!("1,-2,3", a.to_string());
+    }
+
+    #[test]
+    fn test_rotations() {
+        assert_eq!(4, rotations::<2>().len());
+        assert_eq!(24, rotations::<3>().len());
+        assert_eq!(192, rotations::<4>().len());
+
+        let all = rotations::<3>();
+        assert!(all.contains(&Matrix::identity()));
+        for &r in all.iter() {
+            assert_eq!(Matrix::identity(), r * r.transpose());
+            for &s in all.iter() {
+                assert!(all.contains(&(r * s)));
+            }
+        }
+
+        let v = Vector::new([1, 2, 3]);
+        let images = all
+            .iter()
+            .map(|&r| r * v)
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(24, images.len());
+    }
+
+    #[test]
+    fn test_aabb() {
+        let first = Aabb::new(Vector::new([0, 0, 0]), Vector::new([10, 10, 10]));
+        let second = Aabb::new(Vector::new([5, 5, 5]), Vector::new([15, 15, 15]));
+        let far = Aabb::new(Vector::new([20, 0, 0]), Vector::new([30, 1, 1]));
+
+        assert!(first.intersects(&second));
+        assert!(!first.intersects(&far));
+        assert_eq!(125, first.intersection(&second).unwrap().volume());
+
+        let pieces = first.difference(&second);
+        assert_eq!(7, pieces.len());
+        assert_eq!(875, pieces.iter().map(|p| p.volume()).sum::<u64>());
+        assert!(pieces.iter().all(|p| !p.intersects(&second)));
+        assert_eq!(vec![far], far.difference(&first));
+        assert!(second
+            .difference(&Aabb::new(Vector::zero(), Vector::new([20; 3])))
+            .is_empty());
+    }
+}
@@ -0,0 +1,297 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{ParseError, Result};
+use crate::input::{self, InputLine};
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A dense row-major grid indexed by `(row, col)`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(cells: Vec<T>, width: usize, height: usize) -> Self {
+        assert_eq!(cells.len(), width * height);
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| f(row, col))
+            .collect();
+        Self::from_vec(cells, width, height)
+    }
+
+    /// Parses one cell per character, rejecting ragged rows and any character
+    /// `parse_cell` doesn't accept.
+    pub fn from_lines<'a>(
+        lines: impl IntoIterator<Item = InputLine<'a>>,
+        expected: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for line in lines {
+            let text = line.text();
+            for (i, c) in text.char_indices() {
+                let cell = parse_cell(c).ok_or_else(|| {
+                    line.error_at(
+                        &text[i..i + c.len_utf8()],
+                        format!("Unexpected character '{}'.", c),
+                    )
+                    .with_expected(expected)
+                })?;
+                cells.push(cell);
+            }
+
+            let row_width = text.chars().count();
+            if height > 0 && row_width != width {
+                return Err(line
+                    .error(format!("Expected {} cells in row.", width))
+                    .into());
+            }
+            width = row_width;
+            height += 1;
+        }
+
+        Ok(Self::from_vec(cells, width, height))
+    }
+
+    pub fn parse(
+        data: &str,
+        expected: &str,
+        parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let grid = Self::from_lines(input::lines(data), expected, parse_cell)?;
+        if grid.is_empty() {
+            return Err(ParseError::new(1, 1, "Input file is empty.").into());
+        }
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the position at signed coordinates if it lies inside the grid.
+    pub fn position(&self, row: isize, col: isize) -> Option<(usize, usize)> {
+        if row < 0 || col < 0 || row as usize >= self.height || col as usize >= self.width {
+            return None;
+        }
+        Some((row as usize, col as usize))
+    }
+
+    pub fn get(&self, row: isize, col: isize) -> Option<&T> {
+        self.position(row, col).map(|p| &self[p])
+    }
+
+    pub fn get_mut(&mut self, row: isize, col: isize) -> Option<&mut T> {
+        self.position(row, col).map(move |p| &mut self[p])
+    }
+
+    /// Wraps coordinates around the edges, as on a torus.
+    pub fn wrap(&self, row: isize, col: isize) -> (usize, usize) {
+        (
+            row.rem_euclid(self.height as isize) as usize,
+            col.rem_euclid(self.width as isize) as usize,
+        )
+    }
+
+    pub fn get_wrapping(&self, row: isize, col: isize) -> &T {
+        &self[self.wrap(row, col)]
+    }
+
+    pub fn get_wrapping_mut(&mut self, row: isize, col: isize) -> &mut T {
+        let position = self.wrap(row, col);
+        &mut self[position]
+    }
+
+    pub fn neighbours4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(row, col, &ORTHOGONAL)
+    }
+
+    pub fn neighbours8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(row, col, &ADJACENT)
+    }
+
+    fn offsets(
+        &self,
+        row: usize,
+        col: usize,
+        deltas: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        deltas
+            .iter()
+            .filter_map(move |&(dr, dc)| self.position(row as isize + dr, col as isize + dc))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width);
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.cells.iter().map(f).collect(), self.width, self.height)
+    }
+
+    /// Draws the grid with one character per cell and a newline after each
+    /// row.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&mut f));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self::from_vec(vec![value; width * height], width, height)
+    }
+}
+
+impl Grid<u8> {
+    pub fn parse_digits(data: &str) -> Result<Self> {
+        Self::parse(data, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.height && col < self.width);
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.height && col < self.width);
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse_digits("123\n456").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(1, 2)]);
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(vec![2, 5], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!("123\n456\n", grid.to_string());
+
+        match Grid::parse_digits("12\n3x") {
+            Err(Error::Parse(e)) => assert_eq!((2, 2), (e.line(), e.column())),
+            other => panic!("Unexpected result {:?}.", other),
+        }
+        assert!(Grid::parse_digits("12\n3").is_err());
+        assert!(Grid::parse_digits("").is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbours4(0, 0).collect::<Vec<_>>()
+        );
+        assert_eq!(8, grid.neighbours8(1, 1).count());
+        assert_eq!(3, grid.neighbours8(2, 2).count());
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = Grid::from_fn(3, 2, |row, col| row * 3 + col);
+        assert_eq!(None, grid.get(-1, 0));
+        assert_eq!(Some(&5), grid.get(1, 2));
+        assert_eq!(&0, grid.get_wrapping(2, 3));
+        assert_eq!(&5, grid.get_wrapping(-1, -1));
+
+        *grid.get_wrapping_mut(0, 4) = 9;
+        assert_eq!(9, grid[(0, 1)]);
+        assert_eq!(
+            "##.\n...\n",
+            grid.render(|&v| if v % 9 == 0 { '#' } else { '.' })
+        );
+    }
+}
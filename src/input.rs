@@ -0,0 +1,201 @@
+use std::io::BufRead;
+use std::str;
+
+use crate::error::{ParseError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputLine<'a> {
+    number: usize,
+    column: usize,
+    text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self::starting_at(number, 1, text)
+    }
+
+    /// A piece of line `number` that starts at `column`, such as one field of
+    /// a comma separated list read on its own.
+    pub fn starting_at(number: usize, column: usize, text: &'a str) -> Self {
+        Self {
+            number,
+            column,
+            text,
+        }
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn column_of(&self, part: &str) -> usize {
+        let begin = self.text.as_ptr() as usize;
+        let position = part.as_ptr() as usize;
+        if position < begin || position > begin + self.text.len() {
+            return self.column;
+        }
+        self.text[..position - begin].chars().count() + self.column
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column, message)
+    }
+
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(part), message)
+            .with_length(part.chars().count())
+    }
+
+    pub fn error_at_end(&self, message: impl Into<String>) -> ParseError {
+        self.error_at(&self.text[self.text.len()..], message)
+    }
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| InputLine::new(i + 1, text))
+}
+
+/// Reads records lazily from any [`BufRead`], for inputs too large to hold in
+/// memory. Records are whole lines, or with [`Reader::fields`] the pieces of
+/// each line between delimiters, numbered as [`lines`] would number them.
+pub struct Reader<R> {
+    reader: R,
+    delimiter: u8,
+    buffer: Vec<u8>,
+    line: usize,
+    column: usize,
+}
+
+impl<R: BufRead> Reader<R> {
+    pub fn lines(reader: R) -> Self {
+        Self::fields(reader, b'\n')
+    }
+
+    pub fn fields(reader: R, delimiter: u8) -> Self {
+        Self {
+            reader,
+            delimiter,
+            buffer: Vec::new(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// The next record, or `None` at the end of the input. The record borrows
+    /// the reader's buffer, so only the longest record is ever held.
+    pub fn next_record(&mut self) -> Result<Option<InputLine<'_>>> {
+        let delimiter = self.delimiter;
+        self.buffer.clear();
+        let mut terminator = None;
+        loop {
+            let available = self.reader.fill_buf()?;
+            if available.is_empty() {
+                break;
+            }
+            match available.iter().position(|&b| b == delimiter || b == b'\n') {
+                Some(end) => {
+                    self.buffer.extend_from_slice(&available[..end]);
+                    terminator = Some(available[end]);
+                    self.reader.consume(end + 1);
+                    break;
+                }
+                None => {
+                    let length = available.len();
+                    self.buffer.extend_from_slice(available);
+                    self.reader.consume(length);
+                }
+            }
+        }
+        if terminator.is_none() && self.buffer.is_empty() {
+            return Ok(None);
+        }
+        if terminator == Some(b'\n') && self.buffer.last() == Some(&b'\r') {
+            self.buffer.pop();
+        }
+
+        let (line, column) = (self.line, self.column);
+        let text = str::from_utf8(&self.buffer).map_err(|e| {
+            let valid = str::from_utf8(&self.buffer[..e.valid_up_to()]).unwrap_or_default();
+            ParseError::new(line, column + valid.chars().count(), "Invalid UTF-8.").with_source(e)
+        })?;
+        if terminator == Some(b'\n') {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += text.chars().count() + 1;
+        }
+
+        Ok(Some(InputLine::starting_at(line, column, text)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let lines = lines("ab\ncd").collect::<Vec<_>>();
+        assert_eq!(
+            vec![InputLine::new(1, "ab"), InputLine::new(2, "cd")],
+            lines
+        );
+    }
+
+    #[test]
+    fn test_column_of() {
+        let line = InputLine::new(4, "on x=1..2,y=3..4");
+        let part = line.text().split(',').nth(1).unwrap();
+        assert_eq!(11, line.column_of(part));
+
+        let error = line.error_at_end("Missing value.");
+        assert_eq!((4, 17), (error.line(), error.column()));
+    }
+
+    #[test]
+    fn test_reader() {
+        // A tiny buffer makes records span several reads.
+        let data = "ab\r\n\ncdé\nf";
+        let mut reader = Reader::lines(io::BufReader::with_capacity(2, data.as_bytes()));
+        let mut records = Vec::new();
+        while let Some(line) = reader.next_record().unwrap() {
+            records.push((line.number(), line.text().to_string()));
+        }
+        let expected = lines(data)
+            .map(|line| (line.number(), line.text().to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, records);
+
+        let mut reader = Reader::fields("3,é4\n,5".as_bytes(), b',');
+        reader.next_record().unwrap();
+        let field = reader.next_record().unwrap().unwrap();
+        let error = field.error_at(&field.text()[2..], "Bad digit.");
+        assert_eq!((1, 4), (error.line(), error.column()));
+        assert_eq!(
+            Some((2, 1)),
+            reader
+                .next_record()
+                .unwrap()
+                .map(|f| (f.number(), f.column_of(f.text())))
+        );
+        assert_eq!("5", reader.next_record().unwrap().unwrap().text());
+        assert_eq!(None, reader.next_record().unwrap());
+
+        let mut reader = Reader::lines(&b"ok\nx\xff"[..]);
+        reader.next_record().unwrap();
+        match reader.next_record() {
+            Err(crate::error::Error::Parse(e)) => assert_eq!((2, 2), (e.line(), e.column())),
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,306 @@
+//! Named counters and histograms for seeing where a solver spends its effort.
+//!
+//! Recording is compiled in only with the `instrument` cargo feature; without
+//! it [`count`] and [`observe`] are no-ops and [`record`] always returns empty
+//! metrics.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(feature = "json")]
+use crate::json;
+
+pub const ENABLED: bool = cfg!(feature = "instrument");
+
+/// Summary of the values observed under one name, with power-of-two buckets:
+/// bucket 0 holds zeros and bucket `i` values in `2^(i-1)..2^i`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    pub count: u64,
+    pub sum: u64,
+    pub min: u64,
+    pub max: u64,
+    pub buckets: Vec<u64>,
+}
+
+impl Histogram {
+    pub fn add(&mut self, value: u64) {
+        if self.count == 0 || value < self.min {
+            self.min = value;
+        }
+        self.max = self.max.max(value);
+        self.count += 1;
+        self.sum = self.sum.saturating_add(value);
+
+        let bucket = (u64::BITS - value.leading_zeros()) as usize;
+        if self.buckets.len() <= bucket {
+            self.buckets.resize(bucket + 1, 0);
+        }
+        self.buckets[bucket] += 1;
+    }
+
+    pub fn merge(&mut self, other: &Histogram) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 || other.min < self.min {
+            self.min = other.min;
+        }
+        self.max = self.max.max(other.max);
+        self.count += other.count;
+        self.sum = self.sum.saturating_add(other.sum);
+
+        if self.buckets.len() < other.buckets.len() {
+            self.buckets.resize(other.buckets.len(), 0);
+        }
+        for (bucket, &count) in self.buckets.iter_mut().zip(other.buckets.iter()) {
+            *bucket += count;
+        }
+    }
+
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.sum as f64 / self.count as f64
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<&Histogram> for json::Value {
+    fn from(histogram: &Histogram) -> Self {
+        json::Value::object()
+            .with("count", histogram.count)
+            .with("min", histogram.min)
+            .with("max", histogram.max)
+            .with("mean", histogram.mean())
+            .with("buckets", histogram.buckets.clone())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Metrics {
+    pub counters: BTreeMap<&'static str, u64>,
+    pub histograms: BTreeMap<&'static str, Histogram>,
+}
+
+impl Metrics {
+    pub fn is_empty(&self) -> bool {
+        self.counters.is_empty() && self.histograms.is_empty()
+    }
+
+    pub fn merge(&mut self, other: &Metrics) {
+        for (name, value) in other.counters.iter() {
+            *self.counters.entry(name).or_default() += value;
+        }
+        for (name, histogram) in other.histograms.iter() {
+            self.histograms.entry(name).or_default().merge(histogram);
+        }
+    }
+}
+
+impl fmt::Display for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, value) in self.counters.iter() {
+            writeln!(f, "Counter {}: {}", name, value)?;
+        }
+        for (name, histogram) in self.histograms.iter() {
+            writeln!(
+                f,
+                "Histogram {}: {} samples, min {}, mean {:.1}, max {}",
+                name,
+                histogram.count,
+                histogram.min,
+                histogram.mean(),
+                histogram.max
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<&Metrics> for json::Value {
+    fn from(metrics: &Metrics) -> Self {
+        let counters = metrics
+            .counters
+            .iter()
+            .fold(json::Value::object(), |object, (name, &value)| {
+                object.with(*name, value)
+            });
+        let histograms = metrics
+            .histograms
+            .iter()
+            .fold(json::Value::object(), |object, (name, histogram)| {
+                object.with(*name, histogram)
+            });
+        json::Value::object()
+            .with("counters", counters)
+            .with("histograms", histograms)
+    }
+}
+
+#[cfg(feature = "instrument")]
+mod recording {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::{Cell, RefCell};
+
+    use super::Metrics;
+
+    thread_local! {
+        pub static RECORDING: Cell<bool> = const { Cell::new(false) };
+        pub static METRICS: RefCell<Metrics> = RefCell::new(Metrics::default());
+        // Per thread, so days running side by side don't see each other's
+        // allocations. Const and without destructors, so the allocator can
+        // touch them at any point in a thread's life.
+        static ALLOCATIONS: Cell<(u64, u64)> = const { Cell::new((0, 0)) };
+    }
+
+    /// Wraps the system allocator, counting every allocation. Install it with
+    /// `#[global_allocator]` to have [`super::record`] report allocations.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct CountingAllocator;
+
+    fn count_allocation(bytes: usize) {
+        let _ = ALLOCATIONS.try_with(|a| {
+            let (count, total) = a.get();
+            a.set((count + 1, total + bytes as u64));
+        });
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            count_allocation(layout.size());
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            count_allocation(new_size);
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    /// How many allocations this thread has made, and their total size.
+    pub fn allocations() -> (u64, u64) {
+        ALLOCATIONS.with(|a| a.get())
+    }
+}
+
+#[cfg(feature = "instrument")]
+pub use recording::CountingAllocator;
+
+/// Adds `delta` to the counter `name`.
+#[cfg(feature = "instrument")]
+pub fn count(name: &'static str, delta: u64) {
+    if recording::RECORDING.with(|r| r.get()) {
+        recording::METRICS.with(|m| *m.borrow_mut().counters.entry(name).or_default() += delta);
+    }
+}
+
+#[cfg(not(feature = "instrument"))]
+#[inline(always)]
+pub fn count(_name: &'static str, _delta: u64) {}
+
+/// Adds `value` to the histogram `name`.
+#[cfg(feature = "instrument")]
+pub fn observe(name: &'static str, value: u64) {
+    if recording::RECORDING.with(|r| r.get()) {
+        recording::METRICS.with(|m| {
+            m.borrow_mut()
+                .histograms
+                .entry(name)
+                .or_default()
+                .add(value)
+        });
+    }
+}
+
+#[cfg(not(feature = "instrument"))]
+#[inline(always)]
+pub fn observe(_name: &'static str, _value: u64) {}
+
+/// Runs `f`, collecting everything counted and observed on this thread while
+/// it runs. If a [`CountingAllocator`] is installed, the allocations this
+/// thread makes meanwhile are added as the `allocations` and
+/// `allocated_bytes` counters. Work `f` hands to other threads is only
+/// included once [`merge`]d, as [`crate::pool::map`] does.
+#[cfg(feature = "instrument")]
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Metrics) {
+    let was_recording = recording::RECORDING.with(|r| r.replace(true));
+    let outer = recording::METRICS.with(|m| m.take());
+    let (allocations, bytes) = recording::allocations();
+    let result = f();
+    let (end_allocations, end_bytes) = recording::allocations();
+    recording::RECORDING.with(|r| r.set(was_recording));
+    let mut metrics = recording::METRICS.with(|m| m.replace(outer));
+    if end_allocations > allocations {
+        let counters = &mut metrics.counters;
+        *counters.entry("allocations").or_default() += end_allocations - allocations;
+        *counters.entry("allocated_bytes").or_default() += end_bytes - bytes;
+    }
+    (result, metrics)
+}
+
+#[cfg(not(feature = "instrument"))]
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Metrics) {
+    (f(), Metrics::default())
+}
+
+/// Adds `metrics`, such as those captured on another thread, to this thread's
+/// recording.
+#[cfg(feature = "instrument")]
+pub fn merge(metrics: &Metrics) {
+    if recording::RECORDING.with(|r| r.get()) {
+        recording::METRICS.with(|m| m.borrow_mut().merge(metrics));
+    }
+}
+
+#[cfg(not(feature = "instrument"))]
+#[inline(always)]
+pub fn merge(_metrics: &Metrics) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram() {
+        let mut histogram = Histogram::default();
+        for value in [0, 1, 5, 6, 100] {
+            histogram.add(value);
+        }
+        assert_eq!((5, 0, 100), (histogram.count, histogram.min, histogram.max));
+        assert_eq!(22.4, histogram.mean());
+        assert_eq!(vec![1, 1, 0, 2, 0, 0, 0, 1], histogram.buckets);
+    }
+
+    #[test]
+    fn test_record() {
+        let (value, metrics) = record(|| {
+            count("steps", 2);
+            count("steps", 3);
+            observe("queue", 4);
+            7
+        });
+        assert_eq!(7, value);
+
+        if ENABLED {
+            assert_eq!(Some(&5), metrics.counters.get("steps"));
+            assert_eq!(1, metrics.histograms["queue"].count);
+            assert_eq!(
+                "Counter steps: 5\nHistogram queue: 1 samples, min 4, mean 4.0, max 4\n",
+                metrics.to_string()
+            );
+        } else {
+            assert!(metrics.is_empty());
+        }
+
+        count("ignored", 1);
+        let (_, metrics) = record(|| ());
+        assert!(metrics.is_empty());
+    }
+}
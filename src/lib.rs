@@ -1 +1,206 @@
-pub mod error;
\ No newline at end of file
+//! Advent of Code 2021 solutions, along with each puzzle's input types.
+//!
+//! Every `dayN` module exposes the types its puzzle input parses into. They
+//! implement [`FromStr`](std::str::FromStr) and display back in the puzzle's
+//! own format, so parsing what they display gives an equal value:
+//!
+//! ```
+//! # #[cfg(feature = "day18")]
+//! # fn main() -> Result<(), aoc2021::error::Error> {
+//! use aoc2021::day18::{Homework, Number};
+//!
+//! let homework = "[1,2]\n[[3,4],5]\n".parse::<Homework>()?;
+//! assert_eq!(2, homework.numbers().len());
+//! assert_eq!(homework, homework.to_string().parse()?);
+//!
+//! let number = Number::pair(Number::Regular(9), homework.numbers()[0].clone());
+//! assert_eq!("[9,[1,2]]", number.to_string());
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "day18"))]
+//! # fn main() {}
+//! ```
+//!
+//! Each day's [`Solution`] parses the same types.
+
+pub mod answers;
+pub mod bench;
+pub mod config;
+#[cfg(feature = "day01")]
+pub mod day1;
+#[cfg(feature = "day10")]
+pub mod day10;
+#[cfg(feature = "day11")]
+pub mod day11;
+#[cfg(feature = "day12")]
+pub mod day12;
+#[cfg(feature = "day13")]
+pub mod day13;
+#[cfg(feature = "day14")]
+pub mod day14;
+#[cfg(feature = "day15")]
+pub mod day15;
+#[cfg(feature = "day16")]
+pub mod day16;
+#[cfg(feature = "day17")]
+pub mod day17;
+#[cfg(feature = "day18")]
+pub mod day18;
+#[cfg(feature = "day19")]
+pub mod day19;
+#[cfg(feature = "day02")]
+pub mod day2;
+#[cfg(feature = "day20")]
+pub mod day20;
+#[cfg(feature = "day21")]
+pub mod day21;
+#[cfg(feature = "day22")]
+pub mod day22;
+#[cfg(feature = "day23")]
+pub mod day23;
+#[cfg(feature = "day24")]
+pub mod day24;
+#[cfg(feature = "day25")]
+pub mod day25;
+#[cfg(feature = "day03")]
+pub mod day3;
+#[cfg(feature = "day04")]
+pub mod day4;
+#[cfg(feature = "day05")]
+pub mod day5;
+#[cfg(feature = "day06")]
+pub mod day6;
+#[cfg(feature = "day07")]
+pub mod day7;
+#[cfg(feature = "day08")]
+pub mod day8;
+#[cfg(feature = "day09")]
+pub mod day9;
+pub mod diagnostic;
+pub mod error;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod instrument;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod memo;
+pub mod pool;
+pub mod search;
+pub mod simulation;
+pub mod snapshot;
+pub mod solution;
+pub mod traverse;
+pub mod viz;
+
+pub use solution::Solution;
+
+pub const NUM_DAYS: usize = 25;
+
+macro_rules! select_day {
+    ($day:expr, $($func:ident)::+ as $type:ty) => {
+        match $day {
+            #[cfg(feature = "day01")]
+            1 => Some($($func)::+::<day1::Day1> as $type),
+            #[cfg(feature = "day02")]
+            2 => Some($($func)::+::<day2::Day2> as $type),
+            #[cfg(feature = "day03")]
+            3 => Some($($func)::+::<day3::Day3> as $type),
+            #[cfg(feature = "day04")]
+            4 => Some($($func)::+::<day4::Day4> as $type),
+            #[cfg(feature = "day05")]
+            5 => Some($($func)::+::<day5::Day5> as $type),
+            #[cfg(feature = "day06")]
+            6 => Some($($func)::+::<day6::Day6> as $type),
+            #[cfg(feature = "day07")]
+            7 => Some($($func)::+::<day7::Day7> as $type),
+            #[cfg(feature = "day08")]
+            8 => Some($($func)::+::<day8::Day8> as $type),
+            #[cfg(feature = "day09")]
+            9 => Some($($func)::+::<day9::Day9> as $type),
+            #[cfg(feature = "day10")]
+            10 => Some($($func)::+::<day10::Day10> as $type),
+            #[cfg(feature = "day11")]
+            11 => Some($($func)::+::<day11::Day11> as $type),
+            #[cfg(feature = "day12")]
+            12 => Some($($func)::+::<day12::Day12> as $type),
+            #[cfg(feature = "day13")]
+            13 => Some($($func)::+::<day13::Day13> as $type),
+            #[cfg(feature = "day14")]
+            14 => Some($($func)::+::<day14::Day14> as $type),
+            #[cfg(feature = "day15")]
+            15 => Some($($func)::+::<day15::Day15> as $type),
+            #[cfg(feature = "day16")]
+            16 => Some($($func)::+::<day16::Day16> as $type),
+            #[cfg(feature = "day17")]
+            17 => Some($($func)::+::<day17::Day17> as $type),
+            #[cfg(feature = "day18")]
+            18 => Some($($func)::+::<day18::Day18> as $type),
+            #[cfg(feature = "day19")]
+            19 => Some($($func)::+::<day19::Day19> as $type),
+            #[cfg(feature = "day20")]
+            20 => Some($($func)::+::<day20::Day20> as $type),
+            #[cfg(feature = "day21")]
+            21 => Some($($func)::+::<day21::Day21> as $type),
+            #[cfg(feature = "day22")]
+            22 => Some($($func)::+::<day22::Day22> as $type),
+            #[cfg(feature = "day23")]
+            23 => Some($($func)::+::<day23::Day23> as $type),
+            #[cfg(feature = "day24")]
+            24 => Some($($func)::+::<day24::Day24> as $type),
+            #[cfg(feature = "day25")]
+            25 => Some($($func)::+::<day25::Day25> as $type),
+            _ => None,
+        }
+    };
+}
+
+pub type Solver = fn(&str, &config::Settings) -> error::Result<(String, String)>;
+
+#[cfg(feature = "bench")]
+pub type Benchmark = fn(&str, &config::Settings, usize) -> error::Result<bench::Timings>;
+
+pub type Reporter = fn(&str, &config::Settings) -> error::Result<solution::Report>;
+
+pub fn solver(day: usize) -> Option<Solver> {
+    select_day!(day, solution::solve as Solver)
+}
+
+#[cfg(feature = "bench")]
+pub fn benchmark(day: usize) -> Option<Benchmark> {
+    select_day!(day, bench::benchmark as Benchmark)
+}
+
+pub fn reporter(day: usize) -> Option<Reporter> {
+    select_day!(day, solution::report as Reporter)
+}
+
+pub type Streamer =
+    fn(&mut dyn std::io::BufRead, &config::Settings) -> error::Result<(String, String)>;
+
+/// Only the days whose algorithms work online can be streamed.
+pub fn streamer(day: usize) -> Option<Streamer> {
+    match day {
+        #[cfg(feature = "day01")]
+        1 => Some(solution::solve_stream::<day1::Day1> as Streamer),
+        #[cfg(feature = "day02")]
+        2 => Some(solution::solve_stream::<day2::Day2> as Streamer),
+        #[cfg(feature = "day03")]
+        3 => Some(solution::solve_stream::<day3::Day3> as Streamer),
+        #[cfg(feature = "day06")]
+        6 => Some(solution::solve_stream::<day6::Day6> as Streamer),
+        #[cfg(feature = "day07")]
+        7 => Some(solution::solve_stream::<day7::Day7> as Streamer),
+        #[cfg(feature = "day10")]
+        10 => Some(solution::solve_stream::<day10::Day10> as Streamer),
+        _ => None,
+    }
+}
+
+pub type Simulator =
+    fn(&str, &config::Settings) -> error::Result<Option<Box<dyn simulation::Simulation>>>;
+
+pub fn simulator(day: usize) -> Option<Simulator> {
+    select_day!(day, simulation::start as Simulator)
+}
@@ -0,0 +1,270 @@
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+
+#[cfg(feature = "json")]
+use crate::json;
+
+/// 64-bit FNV-1a, which is much cheaper than the default SipHash for the
+/// small integer keys most puzzles memoize on.
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+pub type FnvBuildHasher = BuildHasherDefault<FnvHasher>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+    pub evictions: usize,
+}
+
+impl CacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / lookups as f64
+    }
+}
+
+/// A memo table that counts its hits and misses and, when bounded, evicts the
+/// oldest entry to make room for a new one.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V, S = RandomState> {
+    name: &'static str,
+    entries: HashMap<K, V, S>,
+    limit: Option<usize>,
+    insertion_order: VecDeque<K>,
+    stats: CacheStats,
+}
+
+impl<K, V> Memo<K, V> {
+    pub fn new(name: &'static str) -> Self {
+        Self::with_hasher(name, RandomState::new())
+    }
+}
+
+impl<K, V, S> Memo<K, V, S> {
+    pub fn with_hasher(name: &'static str, hasher: S) -> Self {
+        Self {
+            name,
+            entries: HashMap::with_hasher(hasher),
+            limit: None,
+            insertion_order: VecDeque::new(),
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Keeps at most `limit` entries.
+    pub fn bounded(mut self, limit: usize) -> Self {
+        self.limit = Some(limit.max(1));
+        self
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self.entries.len(),
+            ..self.stats
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, S: BuildHasher> Memo<K, V, S> {
+    /// Looks up `key`, counting the lookup as a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let value = self.entries.get(key);
+        if value.is_some() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        if let Some(limit) = self.limit {
+            if !self.entries.contains_key(&key) {
+                while self.entries.len() >= limit {
+                    let Some(oldest) = self.insertion_order.pop_front() else {
+                        break;
+                    };
+                    self.entries.remove(&oldest);
+                    self.stats.evictions += 1;
+                }
+                self.insertion_order.push_back(key.clone());
+            }
+        }
+        self.entries.insert(key, value);
+    }
+
+    /// Returns the cached value for `key`, computing and storing it on a miss.
+    /// `compute` can't consult the memo itself; recursive functions should
+    /// pair [`Memo::get`] with [`Memo::insert`] instead.
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce() -> V) -> &V {
+        if self.get(&key).is_none() {
+            let value = compute();
+            self.insert(key.clone(), value);
+        }
+        &self.entries[&key]
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.insertion_order.clear();
+    }
+}
+
+impl<K, V, S> Drop for Memo<K, V, S> {
+    fn drop(&mut self) {
+        if RECORDING.with(|r| r.get()) {
+            let report = CacheReport {
+                name: self.name,
+                stats: self.stats(),
+            };
+            REPORTS.with(|reports| reports.borrow_mut().push(report));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheReport {
+    pub name: &'static str,
+    pub stats: CacheStats,
+}
+
+impl fmt::Display for CacheReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} hits, {} misses ({:.1}% hit rate), {} entries, {} evictions",
+            self.name,
+            self.stats.hits,
+            self.stats.misses,
+            self.stats.hit_rate() * 100.0,
+            self.stats.entries,
+            self.stats.evictions
+        )
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<CacheReport> for json::Value {
+    fn from(report: CacheReport) -> Self {
+        json::Value::object()
+            .with("name", report.name)
+            .with("hits", report.stats.hits)
+            .with("misses", report.stats.misses)
+            .with("entries", report.stats.entries)
+            .with("evictions", report.stats.evictions)
+    }
+}
+
+thread_local! {
+    static RECORDING: Cell<bool> = const { Cell::new(false) };
+    static REPORTS: RefCell<Vec<CacheReport>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs `f`, collecting the final statistics of every memo dropped on this
+/// thread while it runs.
+pub fn record_stats<T>(f: impl FnOnce() -> T) -> (T, Vec<CacheReport>) {
+    let was_recording = RECORDING.with(|r| r.replace(true));
+    let outer = REPORTS.with(|reports| reports.take());
+
+    let result = f();
+
+    RECORDING.with(|r| r.set(was_recording));
+    let reports = REPORTS.with(|reports| reports.replace(outer));
+    (result, reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut memo = Memo::with_hasher("squares", FnvBuildHasher::default()).bounded(2);
+        for n in [1, 2, 1, 3, 1] {
+            memo.get_or_insert_with(n, || n * n);
+        }
+
+        assert_eq!(
+            CacheStats {
+                hits: 1,
+                misses: 4,
+                entries: 2,
+                evictions: 2,
+            },
+            memo.stats()
+        );
+        assert_eq!(Some(&1), memo.get(&1));
+        assert_eq!(None, memo.get(&2));
+    }
+
+    #[test]
+    fn test_record_stats() {
+        let (value, reports) = record_stats(|| {
+            let mut memo = Memo::new("fibonacci");
+            fibonacci(&mut memo, 20)
+        });
+
+        assert_eq!(6765, value);
+        assert_eq!(1, reports.len());
+        assert_eq!("fibonacci", reports[0].name);
+        assert_eq!(21, reports[0].stats.entries);
+        assert_eq!(21, reports[0].stats.misses);
+        assert_eq!(18, reports[0].stats.hits);
+
+        let (_, reports) = record_stats(|| ());
+        assert!(reports.is_empty());
+    }
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if let Some(&value) = memo.get(&n) {
+            return value;
+        }
+        let value = if n < 2 {
+            n
+        } else {
+            fibonacci(memo, n - 1) + fibonacci(memo, n - 2)
+        };
+        memo.insert(n, value);
+        value
+    }
+}
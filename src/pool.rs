@@ -0,0 +1,111 @@
+//! A small work pool on scoped std threads for embarrassingly parallel loops.
+//!
+//! The number of threads is process-wide and defaults to one, in which case
+//! [`map`] runs on the calling thread. Calls made from inside a worker also
+//! run inline, so nesting a parallel loop inside another does not
+//! oversubscribe the machine. Counters recorded by [`crate::instrument`] on a
+//! worker, allocations included, are added to the calling thread's recording.
+
+use std::cell::Cell;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::instrument;
+
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    static IN_WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets how many threads [`map`] may use. Zero is treated as one.
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads.max(1), Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+/// Applies `f` to every item, spreading the items over the pool's threads,
+/// and returns the results in the same order as `items`.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    map_with(threads(), items, f)
+}
+
+/// As [`map`], but with up to `threads` threads instead of the pool's.
+pub fn map_with<T: Sync, R: Send>(
+    threads: usize,
+    items: &[T],
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let threads = if IN_WORKER.with(|w| w.get()) {
+        1
+    } else {
+        threads.min(items.len())
+    };
+    if threads <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let work = || {
+        IN_WORKER.with(|w| w.set(true));
+        instrument::record(|| {
+            let mut done = Vec::new();
+            loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                match items.get(index) {
+                    Some(item) => done.push((index, f(item))),
+                    None => break done,
+                }
+            }
+        })
+    };
+    let finished = thread::scope(|scope| {
+        let workers = (0..threads).map(|_| scope.spawn(work)).collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect::<Vec<_>>()
+    });
+
+    let mut results = items.iter().map(|_| None).collect::<Vec<_>>();
+    for (done, metrics) in finished {
+        instrument::merge(&metrics);
+        for (index, result) in done {
+            results[index] = Some(result);
+        }
+    }
+    results
+        .into_iter()
+        .map(|result| result.expect("Every item is mapped by a worker."))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items = (0..100).collect::<Vec<u64>>();
+        let squares = items.iter().map(|x| x * x).collect::<Vec<_>>();
+        assert_eq!(squares, map(&items, |x| x * x));
+        assert_eq!(squares, map_with(4, &items, |x| x * x));
+
+        let nested = map_with(4, &items[..4], |&x| {
+            map_with(4, &items, |y| x * y).iter().sum::<u64>()
+        });
+        assert_eq!(vec![0, 4950, 9900, 14850], nested);
+        assert!(map_with(4, &[] as &[u64], |x| *x).is_empty());
+
+        let (_, metrics) = instrument::record(|| {
+            map_with(4, &items, |_| instrument::count("items", 1));
+        });
+        if instrument::ENABLED {
+            assert_eq!(Some(&100), metrics.counters.get("items"));
+        }
+    }
+}
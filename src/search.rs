@@ -0,0 +1,204 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::instrument;
+
+/// Counters describing how much of the graph a search explored.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    /// Nodes popped from the frontier and expanded.
+    pub expanded: usize,
+    /// Nodes pushed onto the frontier, including re-pushes on cheaper paths.
+    pub pushed: usize,
+    /// Distinct nodes reached.
+    pub discovered: usize,
+    /// Largest size the frontier grew to.
+    pub max_frontier: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// Every node from the start to the goal, inclusive.
+    pub nodes: Vec<N>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<N, C> {
+    pub path: Option<Path<N, C>>,
+    pub stats: Stats,
+}
+
+struct QueueItem<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for QueueItem<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for QueueItem<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for QueueItem<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for QueueItem<N, C> {}
+
+/// Finds the cheapest path from `start` to the first node satisfying
+/// `is_goal`, where `successors` yields each neighbour with its step cost.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// As [`dijkstra`], but ordering the frontier by cost plus `heuristic`, which
+/// must never overestimate the remaining cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut stats = Stats::default();
+    let mut costs = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    costs.insert(start.clone(), C::default());
+    heap.push(QueueItem {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    });
+    stats.pushed += 1;
+    stats.max_frontier = 1;
+
+    while let Some(QueueItem { cost, node, .. }) = heap.pop() {
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        stats.expanded += 1;
+        instrument::observe("search frontier", heap.len() as u64);
+
+        if is_goal(&node) {
+            stats.discovered = costs.len();
+            return SearchResult {
+                path: Some(Path {
+                    cost,
+                    nodes: reconstruct_path(&parents, node),
+                }),
+                stats,
+            };
+        }
+
+        for (next, step_cost) in successors(&node) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_some_and(|&best| best <= next_cost) {
+                continue;
+            }
+
+            costs.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), node.clone());
+            heap.push(QueueItem {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+            stats.pushed += 1;
+        }
+        stats.max_frontier = stats.max_frontier.max(heap.len());
+    }
+
+    stats.discovered = costs.len();
+    SearchResult { path: None, stats }
+}
+
+fn reconstruct_path<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, goal: N) -> Vec<N> {
+    let mut nodes = vec![goal];
+    while let Some(parent) = parents.get(nodes.last().unwrap()) {
+        nodes.push(parent.clone());
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    fn costs() -> Grid<u8> {
+        Grid::parse_digits("1163\n1381\n2136\n3694").unwrap()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = costs();
+        let result = dijkstra(
+            (0, 0),
+            |&(r, c)| grid.neighbours4(r, c).map(|p| (p, grid[p] as u32)),
+            |&p| p == (3, 3),
+        );
+
+        let path = result.path.unwrap();
+        assert_eq!(17, path.cost);
+        assert_eq!(Some(&(0, 0)), path.nodes.first());
+        assert_eq!(Some(&(3, 3)), path.nodes.last());
+        assert_eq!(
+            path.cost,
+            path.nodes[1..].iter().map(|&p| grid[p] as u32).sum::<u32>()
+        );
+        assert!(result.stats.expanded <= result.stats.discovered);
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = costs();
+        let successors = |&(r, c): &(usize, usize)| {
+            grid.neighbours4(r, c)
+                .map(|p| (p, grid[p] as u32))
+                .collect::<Vec<_>>()
+        };
+        let goal = (3, 3);
+        let manhattan = |&(r, c): &(usize, usize)| (goal.0 - r + goal.1 - c) as u32;
+
+        let plain = dijkstra((0, 0), successors, |&p| p == goal);
+        let guided = astar((0, 0), successors, manhattan, |&p| p == goal);
+
+        assert_eq!(plain.path.map(|p| p.cost), guided.path.map(|p| p.cost));
+        assert!(guided.stats.expanded <= plain.stats.expanded);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let result = dijkstra(0_u32, |&n| (n < 5).then_some((n + 1, 1_u32)), |&n| n == 10);
+        assert_eq!(None, result.path);
+        assert_eq!(6, result.stats.expanded);
+    }
+}
@@ -0,0 +1,227 @@
+//! Stepping through a puzzle's simulation one step at a time, for debugging
+//! custom inputs.
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::config::{self, Settings};
+use crate::error::Result;
+use crate::Solution;
+
+/// How many steps [`Command::NextEvent`] takes before giving up.
+pub const EVENT_STEP_LIMIT: usize = 100_000;
+
+/// A puzzle state that advances in discrete steps. Its `Display` shows the
+/// current state.
+pub trait Simulation: fmt::Display {
+    /// Advances one step, describing anything notable that happened, such as
+    /// a bingo board winning.
+    fn step(&mut self) -> Option<String>;
+
+    /// Whether no further steps are possible.
+    fn is_finished(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    NextEvent,
+    Print,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, String> {
+        let words = s.split_whitespace().collect::<Vec<_>>();
+        let command = match words[..] {
+            [] | ["s"] | ["step"] => Command::Step(1),
+            ["s", n] | ["step", n] | ["n", n] | [n] if n.parse::<usize>().is_ok() => {
+                Command::Step(n.parse().unwrap())
+            }
+            ["e"] | ["event"] => Command::NextEvent,
+            ["p"] | ["print"] => Command::Print,
+            ["h"] | ["help"] | ["?"] => Command::Help,
+            ["q"] | ["quit"] => Command::Quit,
+            _ => {
+                return Err(format!(
+                    "Unknown command '{}'; enter 'help' for a list.",
+                    s.trim()
+                ))
+            }
+        };
+        Ok(command)
+    }
+}
+
+const HELP: &str = "Commands:
+  <enter>, s, step   Advance one step
+  n <count>          Advance <count> steps
+  e, event           Advance to the next event
+  p, print           Show the current state
+  h, help            Show this list
+  q, quit            Stop";
+
+fn write_state(
+    output: &mut impl Write,
+    steps: usize,
+    simulation: &dyn Simulation,
+) -> io::Result<()> {
+    let state = simulation.to_string();
+    write!(output, "Step {}\n{}", steps, state)?;
+    if !state.ends_with('\n') {
+        writeln!(output)?;
+    }
+    Ok(())
+}
+
+/// Reads commands from `input` and applies them to `simulation`, writing the
+/// state after each command and any events to `output`.
+pub fn interact(
+    simulation: &mut dyn Simulation,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    let mut steps = 0;
+    write_state(&mut output, 0, simulation)?;
+
+    let mut lines = input.lines();
+    loop {
+        write!(output, "step {}> ", steps)?;
+        output.flush()?;
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => break,
+        };
+
+        let command = match line.parse::<Command>() {
+            Ok(command) => command,
+            Err(e) => {
+                writeln!(output, "{}", e)?;
+                continue;
+            }
+        };
+
+        let (count, until_event) = match command {
+            Command::Step(count) => (count, false),
+            Command::NextEvent => (EVENT_STEP_LIMIT, true),
+            Command::Print => {
+                write_state(&mut output, steps, simulation)?;
+                continue;
+            }
+            Command::Help => {
+                writeln!(output, "{}", HELP)?;
+                continue;
+            }
+            Command::Quit => break,
+        };
+
+        let mut event_seen = false;
+        for _ in 0..count {
+            if simulation.is_finished() {
+                break;
+            }
+            steps += 1;
+            if let Some(event) = simulation.step() {
+                writeln!(output, "Step {}: {}", steps, event)?;
+                event_seen = true;
+                if until_event {
+                    break;
+                }
+            }
+        }
+        if until_event && !event_seen && !simulation.is_finished() {
+            writeln!(output, "No event within {} steps.", count)?;
+        }
+
+        write_state(&mut output, steps, simulation)?;
+        if simulation.is_finished() {
+            writeln!(output, "Simulation finished.")?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn start<S: Solution + Default>(
+    input: &str,
+    settings: &Settings,
+) -> Result<Option<Box<dyn Simulation>>> {
+    let solution = config::configure::<S>(settings)?;
+    let parsed = solution.parse(input)?;
+    Ok(solution.simulation(&parsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Countdown(usize);
+
+    impl fmt::Display for Countdown {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} left", self.0)
+        }
+    }
+
+    impl Simulation for Countdown {
+        fn step(&mut self) -> Option<String> {
+            self.0 -= 1;
+            self.0
+                .is_multiple_of(3)
+                .then(|| format!("{} is a multiple of 3", self.0))
+        }
+
+        fn is_finished(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    #[test]
+    fn test_commands() {
+        assert_eq!(Command::Step(1), "".parse().unwrap());
+        assert_eq!(Command::Step(5), "n 5".parse().unwrap());
+        assert_eq!(Command::Step(12), "12".parse().unwrap());
+        assert_eq!(Command::NextEvent, " e ".parse().unwrap());
+        assert_eq!(Command::Quit, "quit".parse().unwrap());
+        assert!("n x".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn test_interact() {
+        let mut output = Vec::new();
+        let commands = "\ne\nbogus\nn 10\np\n";
+        interact(&mut Countdown(7), commands.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        let expected = "Step 0\n7 left\n\
+                        step 0> Step 1: 6 is a multiple of 3\nStep 1\n6 left\n\
+                        step 1> Step 4: 3 is a multiple of 3\nStep 4\n3 left\n\
+                        step 4> Unknown command 'bogus'; enter 'help' for a list.\n\
+                        step 4> Step 7: 0 is a multiple of 3\nStep 7\n0 left\n\
+                        Simulation finished.\n\
+                        step 7> Step 7\n0 left\n\
+                        step 7> ";
+        assert_eq!(expected, output);
+    }
+
+    #[cfg(feature = "day25")]
+    #[test]
+    fn test_day25_gridlock() {
+        let input = include_str!("../data/day25_test.txt");
+        let mut simulation = start::<crate::day25::Day25>(input, &Settings::default())
+            .unwrap()
+            .unwrap();
+
+        let mut output = Vec::new();
+        interact(simulation.as_mut(), "e\n".as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Step 58: Gridlock"));
+        assert!(simulation.is_finished());
+    }
+}
@@ -0,0 +1,411 @@
+//! Checkpointing long simulations so they can be resumed later.
+//!
+//! A snapshot is plain text: a header of `key value` lines giving the day,
+//! part and step plus any values the day needs, a blank line, and then the
+//! simulation state in the puzzle's own input format:
+//!
+//! ```text
+//! aoc2021 snapshot
+//! day 25
+//! part 1
+//! step 40
+//!
+//! ..>>v>vv..
+//! ```
+//!
+//! Solvers call [`checkpoint`] as they step and [`resume`] before starting;
+//! both do nothing unless [`record`] is active on the thread.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::error::{Error, ParseError, Result};
+use crate::input::{self, InputLine};
+
+const MAGIC: &str = "aoc2021 snapshot";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    day: usize,
+    part: usize,
+    step: usize,
+    values: Vec<(String, String)>,
+    state: String,
+    path: Option<PathBuf>,
+}
+
+impl Snapshot {
+    pub fn new(day: usize, part: usize, step: usize, state: &impl fmt::Display) -> Self {
+        Self {
+            day,
+            part,
+            step,
+            values: Vec::new(),
+            state: state.to_string(),
+            path: None,
+        }
+    }
+
+    /// Records the file the snapshot was read from, for error messages.
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Adds a value the day needs to resume, such as a running total.
+    pub fn with(mut self, key: &str, value: impl fmt::Display) -> Self {
+        self.values.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn day(&self) -> usize {
+        self.day
+    }
+
+    pub fn part(&self) -> usize {
+        self.part
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn value<T: FromStr>(&self, key: &str) -> Result<T> {
+        let (_, value) = self
+            .values
+            .iter()
+            .find(|(k, _)| k == key)
+            .ok_or_else(|| Error::invalid_state(format!("Snapshot has no '{}' value.", key)))?;
+        value.parse().map_err(|_| {
+            Error::invalid_state(format!("Invalid snapshot value '{}' for '{}'.", value, key))
+        })
+    }
+
+    /// Parses the saved state. A parse error points into the snapshot file
+    /// rather than the puzzle input, so it is reported as invalid state with
+    /// the file's name and line.
+    pub fn state<T: FromStr<Err = Error>>(&self) -> Result<T> {
+        self.state.parse().map_err(|e| match e {
+            Error::Parse(e) => {
+                // The magic, day, part and step lines, the values and a blank
+                // line come first.
+                let line = e.line() + self.values.len() + 5;
+                let path = self
+                    .path
+                    .as_ref()
+                    .map_or_else(|| "snapshot".to_string(), |p| p.display().to_string());
+                Error::invalid_state(format!(
+                    "Corrupt state in {} at line {}, column {}: {}",
+                    path,
+                    line,
+                    e.column(),
+                    e.message()
+                ))
+            }
+            e => e,
+        })
+    }
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", MAGIC)?;
+        writeln!(f, "day {}", self.day)?;
+        writeln!(f, "part {}", self.part)?;
+        writeln!(f, "step {}", self.step)?;
+        for (key, value) in self.values.iter() {
+            writeln!(f, "{} {}", key, value)?;
+        }
+        writeln!(f)?;
+        write!(f, "{}", self.state)
+    }
+}
+
+fn parse_number(line: InputLine, value: &str) -> Result<usize> {
+    value.parse().map_err(|e| {
+        line.error_at(value, "Unable to parse integer.")
+            .with_source(e)
+            .into()
+    })
+}
+
+impl FromStr for Snapshot {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (header, state) = s.split_once("\n\n").unwrap_or((s, ""));
+        let mut lines = input::lines(header);
+        match lines.next() {
+            Some(line) if line.text() == MAGIC => {}
+            Some(line) => {
+                return Err(line
+                    .error_at(line.text(), "Not a snapshot.")
+                    .with_expected(format!("'{}'", MAGIC))
+                    .into())
+            }
+            None => return Err(ParseError::new(1, 1, "Snapshot is empty.").into()),
+        }
+
+        let (mut day, mut part, mut step) = (None, None, None);
+        let mut values = Vec::new();
+        for line in lines {
+            let (key, value) = line.text().split_once(' ').ok_or_else(|| {
+                line.error_at(line.text(), "Expected a key and value.")
+                    .with_expected("a line like 'step 40'")
+            })?;
+            match key {
+                "day" => day = Some(parse_number(line, value)?),
+                "part" => part = Some(parse_number(line, value)?),
+                "step" => step = Some(parse_number(line, value)?),
+                _ => values.push((key.to_string(), value.to_string())),
+            }
+        }
+
+        let missing = |key| Error::invalid_state(format!("Snapshot has no '{}' line.", key));
+        Ok(Self {
+            day: day.ok_or_else(|| missing("day"))?,
+            part: part.ok_or_else(|| missing("part"))?,
+            step: step.ok_or_else(|| missing("step"))?,
+            values,
+            state: state.to_string(),
+            path: None,
+        })
+    }
+}
+
+/// What [`record`] does with snapshots: write one to `checkpoint` every
+/// `interval` steps, and hand `resume` to the solver instead of starting from
+/// the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub checkpoint: Option<PathBuf>,
+    pub interval: usize,
+    pub resume: Option<Snapshot>,
+}
+
+impl Options {
+    /// The options for `day` when several days run at once: each checkpoints
+    /// to its own `<checkpoint>.day<n>` so they don't overwrite each other.
+    pub fn for_day(&self, day: usize) -> Self {
+        let checkpoint = self.checkpoint.as_ref().map(|path| {
+            let mut path = path.clone().into_os_string();
+            path.push(format!(".day{}", day));
+            PathBuf::from(path)
+        });
+        Self {
+            checkpoint,
+            ..self.clone()
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            checkpoint: None,
+            interval: 100,
+            resume: None,
+        }
+    }
+}
+
+struct Session {
+    options: Options,
+    written: usize,
+    error: Option<Error>,
+}
+
+thread_local! {
+    static SESSION: RefCell<Option<Session>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, applying `options` to its [`checkpoint`] and [`resume`] calls,
+/// and returns its result with the number of snapshots written.
+pub fn record<T>(options: &Options, f: impl FnOnce() -> T) -> Result<(T, usize)> {
+    let outer = SESSION.with(|s| {
+        s.replace(Some(Session {
+            options: options.clone(),
+            written: 0,
+            error: None,
+        }))
+    });
+    let result = f();
+    let session = SESSION
+        .with(|s| s.replace(outer))
+        .expect("Snapshot session replaced while running.");
+
+    match session.error {
+        Some(e) => Err(e),
+        None => Ok((result, session.written)),
+    }
+}
+
+/// The snapshot to resume part `part` of `day` from, if there is one.
+pub fn resume(day: usize, part: usize) -> Option<Snapshot> {
+    SESSION.with(|s| {
+        s.borrow()
+            .as_ref()
+            .and_then(|session| session.options.resume.as_ref())
+            .filter(|snapshot| snapshot.day == day && snapshot.part == part)
+            .cloned()
+    })
+}
+
+/// The number of steps between checkpoints, if any are being written.
+pub fn interval() -> Option<usize> {
+    SESSION.with(|s| {
+        s.borrow()
+            .as_ref()
+            .filter(|session| session.options.checkpoint.is_some())
+            .map(|session| session.options.interval)
+    })
+}
+
+/// Writes the snapshot built by `make` if `step` is due a checkpoint. `make`
+/// only runs when it is.
+pub fn checkpoint(step: usize, make: impl FnOnce() -> Snapshot) {
+    SESSION.with(|s| {
+        let mut s = s.borrow_mut();
+        let session = match s.as_mut() {
+            Some(session) if session.error.is_none() => session,
+            _ => return,
+        };
+        let path = match &session.options.checkpoint {
+            Some(path) if step.is_multiple_of(session.options.interval) => path,
+            _ => return,
+        };
+
+        match fs::write(path, make().to_string()) {
+            Ok(()) => session.written += 1,
+            Err(e) => {
+                let message = format!("{}: {}", path.display(), e);
+                session.error = Some(io::Error::new(e.kind(), message).into());
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let snapshot = Snapshot::new(11, 2, 40, &"0123\n4567\n").with("flashes", 17);
+        let text = snapshot.to_string();
+        assert_eq!(
+            "aoc2021 snapshot\nday 11\npart 2\nstep 40\nflashes 17\n\n0123\n4567\n",
+            text
+        );
+
+        let parsed = text.parse::<Snapshot>().unwrap();
+        assert_eq!(snapshot, parsed);
+        assert_eq!(17, parsed.value::<usize>("flashes").unwrap());
+        assert!(parsed.value::<usize>("missing").is_err());
+
+        assert!("day 11\n".parse::<Snapshot>().is_err());
+        assert!("aoc2021 snapshot\nday x\n".parse::<Snapshot>().is_err());
+        assert!("aoc2021 snapshot\nday 1\n\n".parse::<Snapshot>().is_err());
+    }
+
+    #[test]
+    fn test_corrupt_state() {
+        #[derive(Debug)]
+        struct Digits;
+
+        impl FromStr for Digits {
+            type Err = Error;
+
+            fn from_str(_s: &str) -> Result<Self> {
+                Err(ParseError::new(2, 3, "Expected a digit.").into())
+            }
+        }
+
+        let text = "aoc2021 snapshot\nday 11\npart 1\nstep 4\nflashes 2\n\n0123\n45x7\n";
+        let snapshot = text.parse::<Snapshot>().unwrap().with_path("day11.snap");
+        match snapshot.state::<Digits>() {
+            Err(Error::InvalidState(message)) => assert_eq!(
+                "Corrupt state in day11.snap at line 8, column 3: Expected a digit.",
+                message
+            ),
+            result => panic!("{:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let path = std::env::temp_dir().join(format!("aoc2021-snapshot-{}", std::process::id()));
+        let options = Options {
+            checkpoint: Some(path.clone()),
+            interval: 3,
+            resume: Some(Snapshot::new(7, 1, 2, &"state\n")),
+        };
+
+        let (resumed, written) = record(&options, || {
+            for step in 1..=7 {
+                checkpoint(step, || Snapshot::new(7, 1, step, &"state\n"));
+            }
+            (resume(7, 1), resume(7, 2))
+        })
+        .unwrap();
+        assert_eq!((Some(2), None), (resumed.0.map(|s| s.step()), resumed.1));
+        assert_eq!(2, written);
+
+        let saved = fs::read_to_string(&path).unwrap().parse::<Snapshot>();
+        assert_eq!(6, saved.unwrap().step());
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(None, resume(7, 1));
+        checkpoint(3, || unreachable!());
+    }
+
+    #[test]
+    fn test_for_day() {
+        let options = Options {
+            checkpoint: Some(PathBuf::from("runs/all.snap")),
+            ..Options::default()
+        };
+        assert_eq!(
+            Some(PathBuf::from("runs/all.snap.day11")),
+            options.for_day(11).checkpoint
+        );
+        assert_eq!(None, Options::default().for_day(11).checkpoint);
+    }
+
+    #[cfg(feature = "day20")]
+    #[test]
+    fn test_day20_resume() {
+        use crate::config::Settings;
+        use crate::solution;
+
+        let input = include_str!("../data/day20_test.txt");
+        let solve = solution::solve::<crate::day20::Day20>;
+        let expected = solve(input, &Settings::default()).unwrap();
+
+        let path = std::env::temp_dir().join(format!("aoc2021-day20-{}", std::process::id()));
+        let mut options = Options {
+            checkpoint: Some(path.clone()),
+            interval: 7,
+            resume: None,
+        };
+        let (results, written) = record(&options, || solve(input, &Settings::default())).unwrap();
+        assert_eq!(expected, results.unwrap());
+        assert_eq!(7, written);
+
+        let snapshot = fs::read_to_string(&path)
+            .unwrap()
+            .parse::<Snapshot>()
+            .unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!((2, 49), (snapshot.part(), snapshot.step()));
+
+        options.checkpoint = None;
+        options.resume = Some(snapshot);
+        let (results, _) = record(&options, || solve(input, &Settings::default())).unwrap();
+        assert_eq!(expected, results.unwrap());
+    }
+}
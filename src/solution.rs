@@ -0,0 +1,96 @@
+use std::fmt;
+use std::io::BufRead;
+use std::time::Instant;
+
+use crate::bench::Timings;
+use crate::config::{self, Settings};
+use crate::error::Result;
+#[cfg(feature = "json")]
+use crate::json;
+use crate::simulation::Simulation;
+use crate::viz;
+
+pub trait Solution {
+    type Input;
+    type Part1: fmt::Display;
+    type Part2: fmt::Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2>;
+
+    #[cfg(feature = "json")]
+    fn extras(&self, _input: &Self::Input) -> Result<json::Value> {
+        Ok(json::Value::object())
+    }
+
+    /// The puzzle's simulation, for the days that can be stepped through.
+    fn simulation(&self, _input: &Self::Input) -> Option<Box<dyn Simulation>> {
+        None
+    }
+
+    fn configure(&mut self, key: &str, _value: &str) -> Result<()> {
+        Err(config::unknown_key(key))
+    }
+}
+
+/// A solution that can work through its input as it is read, holding only a
+/// summary of it, for inputs too large to load as a string.
+pub trait Streaming: Solution {
+    fn solve_stream(&self, reader: &mut dyn BufRead) -> Result<(Self::Part1, Self::Part2)>;
+}
+
+pub fn solve<S: Solution + Default>(input: &str, settings: &Settings) -> Result<(String, String)> {
+    let solution = config::configure::<S>(settings)?;
+    let parsed = solution.parse(input)?;
+    let part1 = viz::phase("part1", || solution.part1(&parsed))?;
+    let part2 = viz::phase("part2", || solution.part2(&parsed))?;
+
+    Ok((part1.to_string(), part2.to_string()))
+}
+
+pub fn solve_stream<S: Streaming + Default>(
+    reader: &mut dyn BufRead,
+    settings: &Settings,
+) -> Result<(String, String)> {
+    let solution = config::configure::<S>(settings)?;
+    let (part1, part2) = solution.solve_stream(reader)?;
+
+    Ok((part1.to_string(), part2.to_string()))
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Report {
+    pub part1: String,
+    pub part2: String,
+    pub timings: Timings,
+    #[cfg(feature = "json")]
+    pub extras: json::Value,
+}
+
+pub fn report<S: Solution + Default>(input: &str, settings: &Settings) -> Result<Report> {
+    let solution = config::configure::<S>(settings)?;
+    let mut timings = Timings::default();
+
+    let start = Instant::now();
+    let parsed = solution.parse(input)?;
+    timings.parse.push(start.elapsed());
+
+    let start = Instant::now();
+    let part1 = viz::phase("part1", || solution.part1(&parsed))?;
+    timings.part1.push(start.elapsed());
+
+    let start = Instant::now();
+    let part2 = viz::phase("part2", || solution.part2(&parsed))?;
+    timings.part2.push(start.elapsed());
+
+    Ok(Report {
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+        timings,
+        #[cfg(feature = "json")]
+        extras: solution.extras(&parsed)?,
+    })
+}
@@ -0,0 +1,312 @@
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::grid::Grid;
+
+/// Records which nodes a traversal has already queued (breadth first) or
+/// reached (depth first).
+pub trait Visited<N> {
+    /// Marks `node` as visited, returning whether it was new.
+    fn visit(&mut self, node: &N) -> bool;
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> for HashSet<N> {
+    fn visit(&mut self, node: &N) -> bool {
+        if self.contains(node) {
+            return false;
+        }
+        self.insert(node.clone());
+        true
+    }
+}
+
+/// Never treats a node as visited, so every route through the graph is
+/// explored separately, as in a search tree.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Tree;
+
+impl<N> Visited<N> for Tree {
+    fn visit(&mut self, _node: &N) -> bool {
+        true
+    }
+}
+
+/// Breadth-first iterator over the nodes reachable from the starting nodes.
+pub struct Bfs<N, F, V> {
+    queue: VecDeque<N>,
+    visited: V,
+    successors: F,
+}
+
+impl<N, F, I, V> Iterator for Bfs<N, F, V>
+where
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    V: Visited<N>,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        for next in (self.successors)(&node) {
+            if self.visited.visit(&next) {
+                self.queue.push_back(next);
+            }
+        }
+        Some(node)
+    }
+}
+
+/// Depth-first iterator over the nodes reachable from the starting nodes.
+pub struct Dfs<N, F, V> {
+    stack: Vec<N>,
+    visited: V,
+    successors: F,
+}
+
+impl<N, F, I, V> Iterator for Dfs<N, F, V>
+where
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    V: Visited<N>,
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Nodes are only marked once popped, so one reached again down a
+        // deeper branch is visited there first, as depth-first requires.
+        loop {
+            let node = self.stack.pop()?;
+            if !self.visited.visit(&node) {
+                continue;
+            }
+            self.stack.extend((self.successors)(&node));
+            return Some(node);
+        }
+    }
+}
+
+fn start<N, V: Visited<N>>(starts: impl IntoIterator<Item = N>, visited: &mut V) -> Vec<N> {
+    starts.into_iter().filter(|n| visited.visit(n)).collect()
+}
+
+/// Visits each reachable node once, nearest first.
+pub fn bfs<N, F, I>(starts: impl IntoIterator<Item = N>, successors: F) -> Bfs<N, F, HashSet<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let queue = start(starts, &mut visited).into();
+    Bfs {
+        queue,
+        visited,
+        successors,
+    }
+}
+
+/// Visits every route from the starting nodes breadth first, yielding a node
+/// again each time it is reached. `successors` must eventually stop
+/// producing nodes or the iterator never ends.
+pub fn bfs_tree<N, F, I>(starts: impl IntoIterator<Item = N>, successors: F) -> Bfs<N, F, Tree>
+where
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    Bfs {
+        queue: starts.into_iter().collect(),
+        visited: Tree,
+        successors,
+    }
+}
+
+/// Visits each reachable node once, following each branch as deep as it goes
+/// before backtracking.
+pub fn dfs<N, F, I>(starts: impl IntoIterator<Item = N>, successors: F) -> Dfs<N, F, HashSet<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut stack = starts.into_iter().collect::<Vec<_>>();
+    stack.reverse();
+    Dfs {
+        stack,
+        visited: HashSet::new(),
+        successors,
+    }
+}
+
+/// The depth-first counterpart of [`bfs_tree`].
+pub fn dfs_tree<N, F, I>(starts: impl IntoIterator<Item = N>, successors: F) -> Dfs<N, F, Tree>
+where
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut stack = starts.into_iter().collect::<Vec<_>>();
+    stack.reverse();
+    Dfs {
+        stack,
+        visited: Tree,
+        successors,
+    }
+}
+
+/// Collects the region around `start` whose nodes all satisfy `predicate`.
+pub fn flood_fill<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut predicate: impl FnMut(&N) -> bool,
+) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    if !predicate(&start) {
+        return Vec::new();
+    }
+
+    bfs([start], |node| {
+        successors(node)
+            .into_iter()
+            .filter(|n| predicate(n))
+            .collect::<Vec<_>>()
+    })
+    .collect()
+}
+
+/// Groups `nodes` into connected components. `successors` must be symmetric,
+/// i.e. describe an undirected graph.
+pub fn connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = Vec::new();
+
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component = bfs([node], &mut successors).collect::<Vec<_>>();
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+
+    components
+}
+
+/// Labels orthogonally connected regions of cells satisfying `include`,
+/// numbering them from 0 in row-major order of their first cell.
+pub fn label_components<T>(
+    grid: &Grid<T>,
+    mut include: impl FnMut(&T) -> bool,
+) -> (Grid<Option<usize>>, usize) {
+    let mut labels = grid.map(|_| None);
+    let mut count = 0;
+
+    for position in grid.positions() {
+        if labels[position].is_some() || !include(&grid[position]) {
+            continue;
+        }
+        let region = flood_fill(
+            position,
+            |&(row, col)| grid.neighbours4(row, col),
+            |&p| include(&grid[p]),
+        );
+        for p in region {
+            labels[p] = Some(count);
+        }
+        count += 1;
+    }
+
+    (labels, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(node: &u32) -> Vec<u32> {
+        match node {
+            0 => vec![1, 2],
+            1 => vec![3],
+            2 => vec![3],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs_dfs() {
+        assert_eq!(vec![0, 1, 2, 3], bfs([0], graph).collect::<Vec<_>>());
+        assert_eq!(vec![0, 2, 3, 1], dfs([0], graph).collect::<Vec<_>>());
+        assert_eq!(
+            vec![0, 1, 2, 3, 3],
+            bfs_tree([0], graph).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![0, 2, 3, 1, 3],
+            dfs_tree([0], graph).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_dfs_diamond() {
+        // Node 1 is reachable from 0 directly and through 2, whose branch is
+        // explored first and reaches 1 before it backtracks.
+        let diamond = |node: &u32| match node {
+            0 => vec![1, 2],
+            1 => vec![3],
+            2 => vec![3, 1],
+            _ => vec![],
+        };
+        assert_eq!(vec![0, 2, 1, 3], dfs([0], diamond).collect::<Vec<_>>());
+        assert_eq!(vec![0, 2, 3, 1], dfs([0, 0], graph).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::parse_digits("199\n129\n919").unwrap();
+        let mut basin = flood_fill((0, 0), |&(r, c)| grid.neighbours4(r, c), |&p| grid[p] != 9);
+        basin.sort();
+        assert_eq!(vec![(0, 0), (1, 0), (1, 1), (2, 1)], basin);
+        assert!(flood_fill((0, 1), |_| Vec::new(), |&p| grid[p] != 9).is_empty());
+    }
+
+    #[test]
+    fn test_components() {
+        let edges = [(1, 2), (3, 4), (4, 5)];
+        let neighbours = |&n: &u32| {
+            edges
+                .iter()
+                .filter_map(|&(a, b)| {
+                    if n == a {
+                        Some(b)
+                    } else if n == b {
+                        Some(a)
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+        let components = connected_components(1..=6, neighbours);
+        assert_eq!(vec![vec![1, 2], vec![3, 4, 5], vec![6]], components);
+
+        let grid = Grid::parse_digits("101\n001\n110").unwrap();
+        let (labels, count) = label_components(&grid, |&v| v == 1);
+        assert_eq!(3, count);
+        assert_eq!(
+            "0.1\n..1\n22.\n",
+            labels.render(|l| match l {
+                Some(l) => char::from_digit(*l as u32, 10).unwrap(),
+                None => '.',
+            })
+        );
+    }
+}
@@ -0,0 +1,385 @@
+//! Frame-by-frame image export for the grid simulations.
+//!
+//! Solvers hand [`frame`] a closure that renders the current state; it only
+//! runs while [`record`] is active. Frames are written as binary PGM images
+//! when the palette is greyscale and as PPM images otherwise, numbered per
+//! [`phase`] so each sequence can be stitched into an animation.
+//!
+//! Recording is compiled in only with the `viz` cargo feature; without it
+//! [`frame`] is a no-op and [`record`] writes nothing.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+
+pub const ENABLED: bool = cfg!(feature = "viz");
+
+pub type Colour = [u8; 3];
+
+/// A gradient through evenly spaced colour stops, mapping level 0 to the
+/// first stop and level 255 to the last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    stops: Vec<Colour>,
+}
+
+impl Palette {
+    pub fn new(stops: Vec<Colour>) -> Result<Self> {
+        if stops.len() < 2 {
+            return Err(Error::invalid_config(
+                "A palette needs at least two colours.",
+            ));
+        }
+        Ok(Self { stops })
+    }
+
+    pub fn grey() -> Self {
+        Self {
+            stops: vec![[0, 0, 0], [255, 255, 255]],
+        }
+    }
+
+    pub fn stops(&self) -> &[Colour] {
+        &self.stops
+    }
+
+    pub fn is_grey(&self) -> bool {
+        self.stops.iter().all(|&[r, g, b]| r == g && g == b)
+    }
+
+    pub fn colour(&self, level: u8) -> Colour {
+        let segments = self.stops.len() - 1;
+        let position = level as usize * segments;
+        let (index, offset) = (position / 255, position % 255);
+        if index == segments {
+            return self.stops[segments];
+        }
+
+        let (from, to) = (self.stops[index], self.stops[index + 1]);
+        let mut colour = [0; 3];
+        for (c, (&a, &b)) in colour.iter_mut().zip(from.iter().zip(to.iter())) {
+            *c = ((a as usize * (255 - offset) + b as usize * offset) / 255) as u8;
+        }
+        colour
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::grey()
+    }
+}
+
+fn parse_colour(hex: &str) -> Option<Colour> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let mut colour = [0; 3];
+    for (i, c) in colour.iter_mut().enumerate() {
+        *c = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).ok()?;
+    }
+    Some(colour)
+}
+
+impl FromStr for Palette {
+    type Err = Error;
+
+    /// Either a named palette (`grey`, `heat` or `ocean`) or a
+    /// comma-separated list of `rrggbb` colours.
+    fn from_str(s: &str) -> Result<Self> {
+        let stops = match s {
+            "grey" | "gray" => return Ok(Self::grey()),
+            "heat" => vec![
+                [0, 0, 0],
+                [128, 0, 0],
+                [255, 128, 0],
+                [255, 255, 128],
+                [255, 255, 255],
+            ],
+            "ocean" => vec![[0, 16, 48], [0, 96, 160], [64, 192, 192], [255, 255, 255]],
+            _ => s
+                .split(',')
+                .map(|hex| {
+                    parse_colour(hex.trim()).ok_or_else(|| {
+                        Error::invalid_config(format!(
+                            "Invalid colour '{}'; expected 'grey', 'heat', 'ocean' or \
+                             a list like '000000,ff8000,ffffff'.",
+                            hex
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+        };
+        Self::new(stops)
+    }
+}
+
+/// One image, with a level from 0 to 255 per pixel that the palette turns
+/// into a colour.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    levels: Grid<u8>,
+}
+
+impl Frame {
+    pub fn new(levels: Grid<u8>) -> Self {
+        Self { levels }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, level: impl FnMut(&T) -> u8) -> Self {
+        Self::new(grid.map(level))
+    }
+
+    pub fn levels(&self) -> &Grid<u8> {
+        &self.levels
+    }
+
+    pub fn write_pgm(&self, palette: &Palette, writer: &mut impl Write) -> io::Result<()> {
+        let (width, height) = (self.levels.width(), self.levels.height());
+        write!(writer, "P5\n{} {}\n255\n", width, height)?;
+        let pixels = self
+            .levels
+            .iter()
+            .map(|&level| palette.colour(level)[0])
+            .collect::<Vec<_>>();
+        writer.write_all(&pixels)
+    }
+
+    pub fn write_ppm(&self, palette: &Palette, writer: &mut impl Write) -> io::Result<()> {
+        let (width, height) = (self.levels.width(), self.levels.height());
+        write!(writer, "P6\n{} {}\n255\n", width, height)?;
+        let pixels = self
+            .levels
+            .iter()
+            .flat_map(|&level| palette.colour(level))
+            .collect::<Vec<_>>();
+        writer.write_all(&pixels)
+    }
+}
+
+/// Where recorded frames go: `<dir>/<prefix>_<phase>_<index>.pgm`, or `.ppm`
+/// for a colour palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    dir: PathBuf,
+    palette: Palette,
+    prefix: String,
+}
+
+impl Output {
+    pub fn new(dir: impl Into<PathBuf>, palette: Palette) -> Self {
+        Self {
+            dir: dir.into(),
+            palette,
+            prefix: String::new(),
+        }
+    }
+
+    pub fn with_prefix(&self, prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            ..self.clone()
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    pub fn path(&self, phase: &str, index: usize) -> PathBuf {
+        let extension = if self.palette.is_grey() { "pgm" } else { "ppm" };
+        let name = if self.prefix.is_empty() {
+            format!("{}_{:05}.{}", phase, index, extension)
+        } else {
+            format!("{}_{}_{:05}.{}", self.prefix, phase, index, extension)
+        };
+        self.dir.join(name)
+    }
+
+    pub fn write(&self, phase: &str, index: usize, frame: &Frame) -> io::Result<()> {
+        let path = self.path(phase, index);
+        let mut writer = io::BufWriter::new(std::fs::File::create(&path)?);
+        if self.palette.is_grey() {
+            frame.write_pgm(&self.palette, &mut writer)?;
+        } else {
+            frame.write_ppm(&self.palette, &mut writer)?;
+        }
+        writer.flush()
+    }
+}
+
+#[cfg(feature = "viz")]
+mod recording {
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+    use std::io;
+
+    use super::Output;
+
+    pub struct Recording {
+        pub output: Output,
+        pub indices: HashMap<&'static str, usize>,
+        pub written: usize,
+        pub error: Option<io::Error>,
+    }
+
+    thread_local! {
+        pub static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+        pub static PHASE: Cell<&'static str> = const { Cell::new("frames") };
+    }
+}
+
+/// Whether frames passed to [`frame`] are currently being written.
+#[cfg(feature = "viz")]
+pub fn recording() -> bool {
+    recording::RECORDING.with(|r| r.borrow().is_some())
+}
+
+#[cfg(not(feature = "viz"))]
+#[inline(always)]
+pub fn recording() -> bool {
+    false
+}
+
+/// Writes the frame built by `make` as the next in the current phase's
+/// sequence. `make` only runs while recording.
+#[cfg(feature = "viz")]
+pub fn frame(make: impl FnOnce() -> Frame) {
+    recording::RECORDING.with(|r| {
+        let mut r = r.borrow_mut();
+        let recording = match r.as_mut() {
+            Some(recording) if recording.error.is_none() => recording,
+            _ => return,
+        };
+        let phase = recording::PHASE.with(|p| p.get());
+        let index = recording.indices.entry(phase).or_default();
+        match recording.output.write(phase, *index, &make()) {
+            Ok(()) => {
+                *index += 1;
+                recording.written += 1;
+            }
+            Err(e) => recording.error = Some(e),
+        }
+    });
+}
+
+#[cfg(not(feature = "viz"))]
+#[inline(always)]
+pub fn frame(_make: impl FnOnce() -> Frame) {}
+
+/// Runs `f` with frames numbered under `name`, e.g. `part1`.
+#[cfg(feature = "viz")]
+pub fn phase<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let outer = recording::PHASE.with(|p| p.replace(name));
+    let result = f();
+    recording::PHASE.with(|p| p.set(outer));
+    result
+}
+
+#[cfg(not(feature = "viz"))]
+#[inline(always)]
+pub fn phase<T>(_name: &'static str, f: impl FnOnce() -> T) -> T {
+    f()
+}
+
+/// Runs `f`, writing every frame produced on this thread to `output`, and
+/// returns its result with the number of frames written.
+#[cfg(feature = "viz")]
+pub fn record<T>(output: &Output, f: impl FnOnce() -> T) -> Result<(T, usize)> {
+    std::fs::create_dir_all(&output.dir)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", output.dir.display(), e)))?;
+
+    let outer = recording::RECORDING.with(|r| {
+        r.replace(Some(recording::Recording {
+            output: output.clone(),
+            indices: Default::default(),
+            written: 0,
+            error: None,
+        }))
+    });
+    let result = f();
+    let recording = recording::RECORDING
+        .with(|r| r.replace(outer))
+        .expect("Recording replaced while running.");
+
+    match recording.error {
+        Some(e) => Err(e.into()),
+        None => Ok((result, recording.written)),
+    }
+}
+
+#[cfg(not(feature = "viz"))]
+pub fn record<T>(_output: &Output, f: impl FnOnce() -> T) -> Result<(T, usize)> {
+    Ok((f(), 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette() {
+        let heat = "heat".parse::<Palette>().unwrap();
+        assert_eq!([0, 0, 0], heat.colour(0));
+        assert_eq!([255, 255, 255], heat.colour(255));
+        assert!(!heat.is_grey());
+
+        let custom = "#000000,ff0000".parse::<Palette>().unwrap();
+        assert_eq!([127, 0, 0], custom.colour(127));
+        assert!(Palette::grey().is_grey());
+        assert!("000000".parse::<Palette>().is_err());
+        assert!("black,white".parse::<Palette>().is_err());
+    }
+
+    #[test]
+    fn test_write_frames() {
+        let frame = Frame::new(Grid::from_vec(vec![0, 255, 128, 64], 2, 2));
+
+        let mut pgm = Vec::new();
+        frame.write_pgm(&Palette::grey(), &mut pgm).unwrap();
+        assert_eq!(b"P5\n2 2\n255\n\x00\xff\x80\x40", &pgm[..]);
+
+        let mut ppm = Vec::new();
+        let palette = "000000,0000ff".parse().unwrap();
+        frame.write_ppm(&palette, &mut ppm).unwrap();
+        assert_eq!(b"P6\n2 2\n255\n", &ppm[..11]);
+        assert_eq!([0, 0, 255], ppm[14..17]);
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = std::env::temp_dir().join(format!("aoc2021-viz-{}", std::process::id()));
+        let output = Output::new(&dir, Palette::grey()).with_prefix("test");
+        let blank = || Frame::new(Grid::new(3, 2, 7));
+
+        let (value, written) = record(&output, || {
+            frame(blank);
+            phase("part1", || {
+                frame(blank);
+                frame(blank);
+            });
+            7
+        })
+        .unwrap();
+        assert_eq!(7, value);
+
+        if ENABLED {
+            assert_eq!(3, written);
+            assert!(dir.join("test_frames_00000.pgm").exists());
+            assert!(dir.join("test_part1_00001.pgm").exists());
+        } else {
+            assert_eq!(0, written);
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+
+        frame(|| unreachable!());
+    }
+}
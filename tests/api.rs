@@ -0,0 +1,222 @@
+// The helpers are unused when every day is compiled out.
+#![allow(dead_code, unused_imports)]
+
+use std::fmt::{Debug, Display};
+use std::fs;
+use std::io::BufReader;
+use std::path::Path;
+use std::str::FromStr;
+
+use aoc2021::config::Settings;
+use aoc2021::error::Error;
+use aoc2021::generate::{self, Rng};
+use aoc2021::*;
+
+/// Every input for `day`: the puzzle data and fixtures, plus a few generated
+/// inputs.
+fn inputs(day: usize) -> Vec<(String, String)> {
+    let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+    let prefix = format!("day{}", day);
+    let mut inputs = fs::read_dir(&data_dir)
+        .expect("Unable to read data directory.")
+        .map(|entry| entry.expect("Unable to read data directory.").path())
+        .filter(|path| {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            stem == prefix || stem.starts_with(&format!("{}_", prefix))
+        })
+        .map(|path| {
+            let input = fs::read_to_string(&path).expect("Unable to read input.");
+            (path.display().to_string(), input)
+        })
+        .collect::<Vec<_>>();
+
+    let generator = generate::generator(day).expect("Unknown day.");
+    for seed in 0..3 {
+        let size = generator.default_size.min(20);
+        let input = (generator.generate)(&mut Rng::new(seed), size);
+        inputs.push((format!("generated seed {}", seed), input));
+    }
+    inputs
+}
+
+fn check_round_trip<T>(day: usize)
+where
+    T: FromStr<Err = Error> + Display + PartialEq + Debug,
+{
+    for (name, input) in inputs(day) {
+        let parsed = input
+            .parse::<T>()
+            .unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let displayed = parsed.to_string();
+        let reparsed = displayed
+            .parse::<T>()
+            .unwrap_or_else(|e| panic!("{}: {}\n{}", name, e, displayed));
+        assert_eq!(parsed, reparsed, "{}", name);
+        assert_eq!(displayed, reparsed.to_string(), "{}", name);
+    }
+}
+
+macro_rules! round_trip_tests {
+    ($($name:ident => $day:expr, $feature:literal, $type:ty),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            #[test]
+            fn $name() {
+                check_round_trip::<$type>($day);
+            }
+        )*
+    };
+}
+
+round_trip_tests! {
+    day1 => 1, "day01", day1::SonarSweep,
+    day2 => 2, "day02", day2::Course,
+    day3 => 3, "day03", day3::DiagnosticReport,
+    day4 => 4, "day04", day4::Bingo,
+    day5 => 5, "day05", day5::Vents,
+    day6 => 6, "day06", day6::School,
+    day7 => 7, "day07", day7::Crabs,
+    day8 => 8, "day08", day8::Notes,
+    day9 => 9, "day09", day9::HeatMap,
+    day10 => 10, "day10", day10::NavigationSubsystem,
+    day11 => 11, "day11", day11::OctopusGrid,
+    day12 => 12, "day12", day12::CaveSystem,
+    day13 => 13, "day13", day13::Manual,
+    day14 => 14, "day14", day14::PolymerFormula,
+    day15 => 15, "day15", day15::Map,
+    day16 => 16, "day16", day16::Transmission,
+    day17 => 17, "day17", day17::Rectangle,
+    day18 => 18, "day18", day18::Homework,
+    day19 => 19, "day19", day19::Scanners<3>,
+    day20 => 20, "day20", day20::TrenchMap,
+    day21 => 21, "day21", day21::StartingPositions,
+    day22 => 22, "day22", day22::RebootSteps,
+    day23 => 23, "day23", day23::Configuration,
+    day24 => 24, "day24", day24::Monad,
+    day25 => 25, "day25", day25::Map,
+}
+
+#[cfg(all(
+    feature = "day02",
+    feature = "day05",
+    feature = "day13",
+    feature = "day16",
+    feature = "day18",
+    feature = "day19",
+    feature = "day22"
+))]
+#[test]
+fn test_items_round_trip() {
+    let command = "forward 5".parse::<day2::Command>().unwrap();
+    assert_eq!(day2::Command::new(day2::Direction::Forward, 5), command);
+
+    let line = "8,0 -> 0,8".parse::<day5::Line>().unwrap();
+    assert_eq!(day5::Point::new(8, 0), line.start());
+    assert_eq!("8,0 -> 0,8", line.to_string());
+    assert!("0,0 -> 1,2".parse::<day5::Line>().is_err());
+
+    let fold = "fold along y=7".parse::<day13::Fold>().unwrap();
+    assert_eq!((day13::Axis::Y, 7), (fold.axis(), fold.position()));
+    assert_eq!("fold along y=7", fold.to_string());
+
+    let number = "[[1,2],3]".parse::<day18::Number>().unwrap();
+    let expected = day18::Number::pair(
+        day18::Number::pair(day18::Number::Regular(1), day18::Number::Regular(2)),
+        day18::Number::Regular(3),
+    );
+    assert_eq!(expected, number);
+    assert_eq!("[[1,2],3]", number.to_string());
+    assert!("[[1,2],,3]".parse::<day18::Number>().is_err());
+    assert!("[[1,2]3]".parse::<day18::Number>().is_err());
+
+    let scanner = "--- scanner 0 ---\n1,2,3\n-4,5,6\n"
+        .parse::<day19::Scanner<3>>()
+        .unwrap();
+    assert_eq!(2, scanner.points().len());
+    assert_eq!("1,2,3\n-4,5,6\n", scanner.to_string());
+
+    let step = "on x=-1..1,y=0..0,z=2..3"
+        .parse::<day22::Instruction>()
+        .unwrap();
+    assert!(step.turn_on());
+    assert_eq!(6, step.cuboid().volume());
+    assert_eq!("on x=-1..1,y=0..0,z=2..3", step.to_string());
+
+    let cuboid = "x=0..1,y=0..1,z=0..1".parse::<day22::Cuboid>().unwrap();
+    assert_eq!(8, cuboid.volume());
+
+    let mut transmission = "D2FE28".parse::<day16::Transmission>().unwrap();
+    assert_eq!(2021, transmission.parser().parse().unwrap());
+    transmission = "8A004A801A8002F478".parse().unwrap();
+    let mut parser = transmission.parser();
+    parser.parse().unwrap();
+    assert_eq!(16, parser.version_sum());
+}
+
+#[cfg(all(feature = "day06", feature = "day14", feature = "day21"))]
+#[test]
+fn test_overflowing_settings() {
+    for (day, key, value) in [
+        (6, "part1_days", "1000"),
+        (14, "part1_steps", "100"),
+        (21, "winning_score", "60"),
+    ] {
+        let solve = solver(day).expect("Unknown day.");
+        let (_, input) = inputs(day).remove(0);
+        let mut settings = Settings::new();
+        settings.set(key, value);
+        match solve(&input, &settings) {
+            Err(Error::Unsolvable(_)) => {}
+            result => panic!("day {}: {:?}", day, result),
+        }
+    }
+
+    let mut settings = Settings::new();
+    settings.set("board_size", "0");
+    let (_, input) = inputs(21).remove(0);
+    match solver(21).unwrap()(&input, &settings) {
+        Err(Error::InvalidConfig(_)) => {}
+        result => panic!("day 21: {:?}", result),
+    }
+}
+
+fn check_streaming(day: usize) {
+    let solve = solver(day).expect("Unknown day.");
+    let stream = streamer(day).expect("Day can't be streamed.");
+    for (name, input) in inputs(day)
+        .into_iter()
+        .chain([("bad".to_string(), "x\n".to_string())])
+    {
+        // A tiny buffer makes records span several reads.
+        let mut reader = BufReader::with_capacity(3, input.as_bytes());
+        let streamed = stream(&mut reader, &Settings::default());
+        match (solve(&input, &Settings::default()), streamed) {
+            (Ok(expected), Ok(streamed)) => assert_eq!(expected, streamed, "{}", name),
+            (Err(expected), Err(streamed)) => {
+                assert_eq!(expected.to_string(), streamed.to_string(), "{}", name)
+            }
+            (expected, streamed) => panic!("{}: {:?} != {:?}", name, expected, streamed),
+        }
+    }
+}
+
+macro_rules! streaming_tests {
+    ($($name:ident => $day:expr, $feature:literal),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            #[test]
+            fn $name() {
+                check_streaming($day);
+            }
+        )*
+    };
+}
+
+streaming_tests! {
+    stream_day1 => 1, "day01",
+    stream_day2 => 2, "day02",
+    stream_day3 => 3, "day03",
+    stream_day6 => 6, "day06",
+    stream_day7 => 7, "day07",
+    stream_day10 => 10, "day10",
+}
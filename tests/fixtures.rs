@@ -0,0 +1,89 @@
+// The helpers are unused when every day is compiled out.
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::Path;
+
+use aoc2021::answers::Answers;
+use aoc2021::config::Settings;
+
+fn check_fixtures(day: usize) {
+    let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+    let answers_data =
+        fs::read_to_string(data_dir.join("answers.toml")).expect("Unable to read answers.");
+    let answers = Answers::parse(&answers_data).expect("Unable to parse answers.");
+    let solve = aoc2021::solver(day).expect("Unknown day.");
+
+    let fixtures = answers
+        .files()
+        .into_iter()
+        .filter(|&(d, file)| d == day && file.contains("_test"))
+        .map(|(_, file)| file)
+        .collect::<Vec<_>>();
+    assert!(!fixtures.is_empty(), "No fixtures found for day {}.", day);
+
+    let mut mismatches = Vec::new();
+    for file in fixtures {
+        let input = fs::read_to_string(data_dir.join(file)).expect("Unable to read fixture.");
+        let (part1, part2) = match solve(&input, &Settings::default()) {
+            Ok(results) => results,
+            Err(e) => {
+                mismatches.push(format!("{}: {}", file, e));
+                continue;
+            }
+        };
+
+        for (part, result) in [(1, part1), (2, part2)] {
+            if let Some(expected) = answers.get(day, file, part) {
+                if expected != result {
+                    mismatches.push(format!(
+                        "{} part {}: expected {:?}, got {:?}",
+                        file, part, expected, result
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+macro_rules! fixture_tests {
+    ($($name:ident => $day:expr, $feature:literal),* $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            #[test]
+            fn $name() {
+                check_fixtures($day);
+            }
+        )*
+    };
+}
+
+fixture_tests! {
+    day1 => 1, "day01",
+    day2 => 2, "day02",
+    day3 => 3, "day03",
+    day4 => 4, "day04",
+    day5 => 5, "day05",
+    day6 => 6, "day06",
+    day7 => 7, "day07",
+    day8 => 8, "day08",
+    day9 => 9, "day09",
+    day10 => 10, "day10",
+    day11 => 11, "day11",
+    day12 => 12, "day12",
+    day13 => 13, "day13",
+    day14 => 14, "day14",
+    day15 => 15, "day15",
+    day16 => 16, "day16",
+    day17 => 17, "day17",
+    day18 => 18, "day18",
+    day19 => 19, "day19",
+    day20 => 20, "day20",
+    day21 => 21, "day21",
+    day22 => 22, "day22",
+    day23 => 23, "day23",
+    day24 => 24, "day24",
+    day25 => 25, "day25",
+}